@@ -4,7 +4,7 @@ use raytracer::rendering::{render, Camera, RenderParams};
 use raytracer::skyboxes::SolidColorSkybox;
 use raytracer::textures::{Noise, SolidColor};
 use raytracer::structures::{Color, Vec3, Point3};
-use raytracer::hittables::{BVHNode, HittableList, Sphere, XYRect};
+use raytracer::hittables::{BVHNode, HittableList, Sphere, Quad};
 use raytracer::materials::{Lambertian, DiffuseLight};
 
 use std::sync::Arc;
@@ -25,7 +25,8 @@ fn main() {
         image_width: WIDTH,
         image_height: HEIGHT,
         num_samples: NUM_SAMPLES,
-        max_ray_depth: MAX_RAY_DEPTH
+        max_ray_depth: MAX_RAY_DEPTH,
+        ..Default::default()
     };
     let camera = Arc::new(Camera::new(Point3::new(26.0, 3.0, 6.0), Point3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 1.0, 0.0), (20.0 as f64).to_radians(), aspect_ratio, 0.0, 10.0, 0.0, 1.0));
     let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.0, 0.0, 0.0)));
@@ -37,7 +38,7 @@ fn main() {
 
     render(world, skybox, camera, &params, move |sampled, _| {
         progress_bar.set(sampled as u64);
-    }).save("./area_light.png");
+    }).image.save("./area_light.png");
 
     let duration = start.elapsed();
 
@@ -58,7 +59,7 @@ fn build_scene() -> HittableList {
     let light_texture = Arc::new(SolidColor::new(Color::new(4.0, 4.0, 4.0)));
     let light_material = Arc::new(DiffuseLight::new(light_texture));
 
-    let light = Arc::new(XYRect::new(3.0, 5.0, 1.0, 3.0, -2.0, light_material.clone()));
+    let light = Arc::new(Quad::xy_rect(3.0, 5.0, 1.0, 3.0, -2.0, light_material.clone()));
     world.add(light);
 
     world
@@ -1,16 +1,17 @@
 extern crate raytracer;
 
-use raytracer::rendering::{render, Camera, RenderParams};
+use raytracer::rendering::{render, Camera, RenderParams, CheckpointParams};
 use raytracer::skyboxes::SolidColorSkybox;
 use raytracer::textures::{SolidColor, ImageTexture, Noise};
 use raytracer::structures::{Color, Vec3, Point3, Transform, Quaternion};
-use raytracer::hittables::{BVHNode, HittableList, Sphere, XZRect, AABox, Instance, MovingInstance, ConstantMedium};
+use raytracer::hittables::{BVHNode, BVHBuilder, HittableList, Sphere, Quad, AABox, Instance, MovingInstance, ConstantMedium};
 use raytracer::materials::{Lambertian, Dieletric, Metal, DiffuseLight};
+use raytracer::random::{self, thread_rng};
 
 use std::sync::Arc;
 use std::time::Instant;
 
-use rand::{thread_rng, Rng};
+use rand::Rng;
 use pbr::ProgressBar;
 
 const WIDTH: usize = 1280;
@@ -26,23 +27,31 @@ fn main() {
         image_width: WIDTH,
         image_height: HEIGHT,
         num_samples: NUM_SAMPLES,
-        max_ray_depth: MAX_RAY_DEPTH
+        max_ray_depth: MAX_RAY_DEPTH,
+        checkpoint: Some(CheckpointParams::new("./book_two.checkpoint", 100, true)),
+        ..Default::default()
     };
     let camera = Arc::new(Camera::new(Point3::new(478.0, 278.0, -600.0), Point3::new(278.0, 278.0, 0.0), Vec3::new(0.0, 1.0, 0.0), (40.0 as f64).to_radians(), aspect_ratio, 0.0, 10.0, 0.0, 1.0));
     let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.0, 0.0, 0.0)));
-    let world = Arc::new(BVHNode::new(&build_scene(), 0.0, 1.0));
+
+    random::reseed(params.seed);
+    let world = BVHBuilder::new(0.0, 1.0).build(&build_scene());
     
     progress_bar.set(0);
 
     let start = Instant::now();
 
-    render(world, skybox, camera, &params, move |sampled, _| {
+    println!("BVH SAH cost: {}", world.sah_cost);
+
+    let result = render(world.root, skybox, camera, &params, move |sampled, _| {
         progress_bar.set(sampled as u64);
-    }).save("./book_two.png");
+    });
+    result.image.save("./book_two.png");
 
     let duration = start.elapsed();
 
     println!("Time Elapsed: {:?}", duration);
+    println!("Statistics: {}", result.statistics.to_json());
 }
 
 fn build_scene() -> HittableList {
@@ -68,12 +77,12 @@ fn build_scene() -> HittableList {
         }
     }
 
-    let ground = Arc::new(BVHNode::new(&ground, 0.0, 1.0));
+    let ground = BVHBuilder::new(0.0, 1.0).build(&ground).root;
     world.add(ground);
 
     let light_texture = Arc::new(SolidColor::new(Color::new(14.0, 14.0, 14.0)));
     let light_material = Arc::new(DiffuseLight::new(light_texture));
-    let light = Arc::new(XZRect::new(123.0, 423.0, 147.0, 412.0, 554.0, light_material));
+    let light = Arc::new(Quad::xz_rect(123.0, 423.0, 147.0, 412.0, 554.0, light_material));
     world.add(light);
 
     let transform_0 = Transform::new(Vec3::new(400.0, 400.0, 200.0), Quaternion::default(), Vec3::new(1.0, 1.0, 1.0));
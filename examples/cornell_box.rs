@@ -4,7 +4,7 @@ use raytracer::rendering::{render, Camera, RenderParams};
 use raytracer::skyboxes::SolidColorSkybox;
 use raytracer::textures::SolidColor;
 use raytracer::structures::{Color, Vec3, Point3, Transform, Quaternion};
-use raytracer::hittables::{BVHNode, HittableList, XYRect, XZRect, YZRect, AABox, Instance};
+use raytracer::hittables::{BVHNode, HittableList, Quad, AABox, Instance};
 use raytracer::materials::{Lambertian, DiffuseLight};
 
 use std::sync::Arc;
@@ -25,7 +25,8 @@ fn main() {
         image_width: WIDTH,
         image_height: HEIGHT,
         num_samples: NUM_SAMPLES,
-        max_ray_depth: MAX_RAY_DEPTH
+        max_ray_depth: MAX_RAY_DEPTH,
+        ..Default::default()
     };
     let camera = Arc::new(Camera::new(Point3::new(278.0, 278.0, -800.0), Point3::new(278.0, 278.0, 0.0), Vec3::new(0.0, 1.0, 0.0), (40.0 as f64).to_radians(), aspect_ratio, 0.0, 10.0, 0.0, 1.0));
     let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.0, 0.0, 0.0)));
@@ -37,7 +38,7 @@ fn main() {
 
     render(world, skybox, camera, &params, move |sampled, _| {
         progress_bar.set(sampled as u64);
-    }).save("./cornell_box.png");
+    }).image.save("./cornell_box.png");
 
     let duration = start.elapsed();
 
@@ -57,12 +58,12 @@ fn build_scene() -> HittableList {
     let white_material = Arc::new(Lambertian::new(white_texture));
     let light_material = Arc::new(DiffuseLight::new(light_texture));
 
-    world.add(Arc::new(YZRect::new(0.0, 555.0, 0.0, 555.0, 555.0, green_material.clone())));
-    world.add(Arc::new(YZRect::new(0.0, 555.0, 0.0, 555.0, 0.0, red_material.clone())));
-    world.add(Arc::new(XZRect::new(213.0, 343.0, 227.0, 332.0, 554.0, light_material.clone())));
-    world.add(Arc::new(XZRect::new(0.0, 555.0, 0.0, 555.0, 0.0, white_material.clone())));
-    world.add(Arc::new(XZRect::new(0.0, 555.0, 0.0, 555.0, 555.0, white_material.clone())));
-    world.add(Arc::new(XYRect::new(0.0, 555.0, 0.0, 555.0, 555.0, white_material.clone())));
+    world.add(Arc::new(Quad::yz_rect(0.0, 555.0, 0.0, 555.0, 555.0, green_material.clone())));
+    world.add(Arc::new(Quad::yz_rect(0.0, 555.0, 0.0, 555.0, 0.0, red_material.clone())));
+    world.add(Arc::new(Quad::xz_rect(213.0, 343.0, 227.0, 332.0, 554.0, light_material.clone())));
+    world.add(Arc::new(Quad::xz_rect(0.0, 555.0, 0.0, 555.0, 0.0, white_material.clone())));
+    world.add(Arc::new(Quad::xz_rect(0.0, 555.0, 0.0, 555.0, 555.0, white_material.clone())));
+    world.add(Arc::new(Quad::xy_rect(0.0, 555.0, 0.0, 555.0, 555.0, white_material.clone())));
 
     let box_0 = Arc::new(AABox::new(Point3::new(0.0, 0.0, 0.0), Point3::new(165.0, 330.0, 165.0), white_material.clone()));
     let box_1 = Arc::new(AABox::new(Point3::new(0.0, 0.0, 0.0), Point3::new(165.0, 165.0, 165.0), white_material.clone()));
@@ -25,7 +25,8 @@ fn main() {
         image_width: WIDTH,
         image_height: HEIGHT,
         num_samples: NUM_SAMPLES,
-        max_ray_depth: MAX_RAY_DEPTH
+        max_ray_depth: MAX_RAY_DEPTH,
+        ..Default::default()
     };
     let camera = Arc::new(Camera::new(Point3::new(13.0, 2.0, 3.0), Point3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), (20.0 as f64).to_radians(), aspect_ratio, 0.0, 10.0, 0.0, 1.0));
     let skybox = Arc::new(GradientSkybox::new(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0), Vec3::new(0.0, 1.0, 0.0)));
@@ -37,7 +38,7 @@ fn main() {
 
     render(world, skybox, camera, &params, move |sampled, _| {
         progress_bar.set(sampled as u64);
-    }).save("./image_texture_sphere.png");
+    }).image.save("./image_texture_sphere.png");
 
     let duration = start.elapsed();
 
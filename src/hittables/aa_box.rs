@@ -1,5 +1,5 @@
 use crate::structures::{Point3, Ray, HitRecord, AABB};
-use crate::hittables::{Hittable, HittableList, XYRect, XZRect, YZRect};
+use crate::hittables::{Hittable, HittableList, Quad};
 use crate::materials::Material;
 
 use std::sync::Arc;
@@ -28,14 +28,14 @@ impl AABox {
     pub fn generate_sides(&mut self) {
         self.sides.hittables.clear();
 
-        self.sides.add(Arc::new(XYRect::new(self.min.x, self.max.x, self.min.y, self.max.y, self.min.z, self.material.clone())));
-        self.sides.add(Arc::new(XYRect::new(self.min.x, self.max.x, self.min.y, self.max.y, self.max.z, self.material.clone())));
+        self.sides.add(Arc::new(Quad::xy_rect(self.min.x, self.max.x, self.min.y, self.max.y, self.min.z, self.material.clone())));
+        self.sides.add(Arc::new(Quad::xy_rect(self.min.x, self.max.x, self.min.y, self.max.y, self.max.z, self.material.clone())));
 
-        self.sides.add(Arc::new(XZRect::new(self.min.x, self.max.x, self.min.z, self.max.z, self.min.y, self.material.clone())));
-        self.sides.add(Arc::new(XZRect::new(self.min.x, self.max.x, self.min.z, self.max.z, self.max.y, self.material.clone())));
+        self.sides.add(Arc::new(Quad::xz_rect(self.min.x, self.max.x, self.min.z, self.max.z, self.min.y, self.material.clone())));
+        self.sides.add(Arc::new(Quad::xz_rect(self.min.x, self.max.x, self.min.z, self.max.z, self.max.y, self.material.clone())));
 
-        self.sides.add(Arc::new(YZRect::new(self.min.y, self.max.y, self.min.z, self.max.z, self.min.x, self.material.clone())));
-        self.sides.add(Arc::new(YZRect::new(self.min.y, self.max.y, self.min.z, self.max.z, self.max.x, self.material.clone())));
+        self.sides.add(Arc::new(Quad::yz_rect(self.min.y, self.max.y, self.min.z, self.max.z, self.min.x, self.material.clone())));
+        self.sides.add(Arc::new(Quad::yz_rect(self.min.y, self.max.y, self.min.z, self.max.z, self.max.x, self.material.clone())));
     }
 }
 
@@ -44,6 +44,10 @@ impl Hittable for AABox {
         self.sides.hit(ray, t_min, t_max)
     }
 
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.sides.occluded(ray, t_min, t_max)
+    }
+
     fn bounding_box(&self, _time_0: f64, _time_1: f64) -> Option<AABB> {
         Some(AABB::new(self.min, self.max))
     }
@@ -0,0 +1,183 @@
+use crate::structures::{Ray, HitRecord, Transform, Quaternion, Vec3, AABB};
+use crate::hittables::Hittable;
+use crate::utility::InverseLerp;
+
+use std::f64::consts::PI;
+use std::ops::{Add, Sub, Mul};
+use std::sync::Arc;
+
+const SAMPLES_PER_SEGMENT: usize = 16;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Interpolation {
+    Linear,
+    Cubic
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Keyframe {
+    pub time: f64,
+    pub transform: Transform
+}
+
+impl Keyframe {
+    pub fn new(time: f64, transform: Transform) -> Self {
+        Self {
+            time: time,
+            transform: transform
+        }
+    }
+}
+
+pub struct AnimatedInstance {
+    pub hittable: Arc<dyn Hittable>,
+    pub interpolation: Interpolation,
+    keyframes: Vec<Keyframe>
+}
+
+impl AnimatedInstance {
+    pub fn new(hittable: Arc<dyn Hittable>, keyframes: Vec<Keyframe>, interpolation: Interpolation) -> Self {
+        assert!(!keyframes.is_empty(), "AnimatedInstance needs at least one keyframe.");
+
+        let mut keyframes = keyframes;
+        keyframes.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+
+        Self {
+            hittable: hittable,
+            interpolation: interpolation,
+            keyframes: keyframes
+        }
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    pub fn transform_at(&self, time: f64) -> Transform {
+        let last = self.keyframes.len() - 1;
+
+        if time <= self.keyframes[0].time {
+            return self.keyframes[0].transform
+        }
+
+        if time >= self.keyframes[last].time {
+            return self.keyframes[last].transform
+        }
+
+        let segment = self.keyframes.partition_point(|keyframe| keyframe.time <= time) - 1;
+        let (a, b) = (&self.keyframes[segment], &self.keyframes[segment + 1]);
+        let t = f64::inverse_lerp(a.time, b.time, time);
+
+        match self.interpolation {
+            Interpolation::Linear => Transform::interpolate(a.transform, b.transform, t),
+            Interpolation::Cubic => self.cubic(segment, t)
+        }
+    }
+
+    fn cubic(&self, segment: usize, t: f64) -> Transform {
+        let previous = &self.keyframes[segment.saturating_sub(1)];
+        let (a, b) = (&self.keyframes[segment], &self.keyframes[segment + 1]);
+        let next = &self.keyframes[usize::min(segment + 2, self.keyframes.len() - 1)];
+
+        let times = [previous.time, a.time, b.time, next.time];
+
+        let mut rotations = [previous.transform.rotation, a.transform.rotation, b.transform.rotation, next.transform.rotation];
+        for i in 1..4 {
+            if Quaternion::dot(&rotations[i - 1], &rotations[i]) < 0.0 {
+                rotations[i] = -1.0 * rotations[i];
+            }
+        }
+
+        Transform::new(
+            hermite([previous.transform.translation, a.transform.translation, b.transform.translation, next.transform.translation], times, t),
+            hermite(rotations, times, t).normalized(),
+            hermite([previous.transform.scale, a.transform.scale, b.transform.scale, next.transform.scale], times, t)
+        )
+    }
+
+    fn sample_times(&self, time_0: f64, time_1: f64) -> Vec<f64> {
+        let mut times = vec![time_0, time_1];
+        times.extend(self.keyframes.iter().map(|keyframe| keyframe.time).filter(|&time| time_0 < time && time < time_1));
+        times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut samples = vec![times[0]];
+        for window in times.windows(2) {
+            for i in 1..=SAMPLES_PER_SEGMENT {
+                samples.push(window[0] + (window[1] - window[0]) * i as f64 / SAMPLES_PER_SEGMENT as f64);
+            }
+        }
+
+        samples
+    }
+}
+
+impl Hittable for AnimatedInstance {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let transform = self.transform_at(ray.time);
+        let (ray, scale) = transform.inverse_transform_ray_with_scale(*ray);
+        match self.hittable.hit(&ray, t_min * scale, t_max * scale) {
+            Some(mut record) => {
+                record.point = transform.transform_point(record.point);
+                record.normal = transform.transform_normal(record.normal);
+                record.tangent = record.tangent.map(|tangent| transform.transform_vector(tangent).normalized());
+                record.t /= scale;
+                Some(record)
+            },
+            None => None
+        }
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        let (ray, scale) = self.transform_at(ray.time).inverse_transform_ray_with_scale(*ray);
+        self.hittable.occluded(&ray, t_min * scale, t_max * scale)
+    }
+
+    fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
+        let aabb = self.hittable.bounding_box(time_0, time_1)?;
+        let corners = aabb.get_points();
+
+        let mut points = Vec::new();
+        let mut previous: Option<Vec<Vec3>> = None;
+        let mut padding: f64 = 0.0;
+
+        for time in self.sample_times(time_0, time_1) {
+            let transform = self.transform_at(time);
+            let current: Vec<Vec3> = corners.iter().map(|&corner| transform.transform_point(corner)).collect();
+
+            if let Some(previous) = &previous {
+                for (a, b) in previous.iter().zip(current.iter()) {
+                    padding = f64::max(padding, (*b - *a).length() * PI / 4.0);
+                }
+            }
+
+            points.extend(current.iter().cloned());
+            previous = Some(current);
+        }
+
+        let aabb = AABB::from_points(&points);
+        let padding = Vec3::new(padding, padding, padding);
+
+        Some(AABB::new(aabb.min - padding, aabb.max + padding))
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        self.hittable.acceleration_memory()
+    }
+}
+
+fn hermite<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>>(points: [T; 4], times: [f64; 4], t: f64) -> T {
+    let duration = times[2] - times[1];
+
+    let tangent = |before: usize, after: usize| match times[after] > times[before] {
+        true => (points[after] - points[before]) * (duration / (times[after] - times[before])),
+        false => points[2] - points[1]
+    };
+
+    let m1 = tangent(0, 2);
+    let m2 = tangent(1, 3);
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    points[1] * (2.0 * t3 - 3.0 * t2 + 1.0) + m1 * (t3 - 2.0 * t2 + t) + points[2] * (-2.0 * t3 + 3.0 * t2) + m2 * (t3 - t2)
+}
@@ -0,0 +1,443 @@
+use crate::structures::{AABB, Point3};
+use crate::hittables::{Hittable, HittableList, BVHNode};
+
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const PARALLEL_THRESHOLD: usize = 4096;
+pub(crate) const MAX_DEPTH: usize = 63;
+const SAH_DEPTH: usize = MAX_DEPTH - 32;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BVHBuilder {
+    pub time_0: f64,
+    pub time_1: f64,
+    pub bin_count: usize,
+    pub max_leaf_size: usize,
+    pub traversal_cost: f64,
+    pub intersection_cost: f64,
+    pub threads: usize
+}
+
+pub struct BVHBuild {
+    pub root: Arc<dyn Hittable>,
+    pub sah_cost: f64,
+    pub node_count: usize,
+    pub leaf_count: usize,
+    pub max_depth: usize,
+    pub build_time: Duration,
+    pub threads_used: usize
+}
+
+#[derive(Clone, Copy)]
+struct BuildPrimitive {
+    index: usize,
+    aabb: AABB,
+    centroid: Point3
+}
+
+#[derive(Clone, Copy)]
+struct Bin {
+    count: usize,
+    aabb: Option<AABB>
+}
+
+pub(crate) enum BuildNode {
+    Leaf { aabb: AABB, first: usize, count: usize },
+    Interior { aabb: AABB, axis: usize, left: Box<BuildNode>, right: Box<BuildNode> }
+}
+
+impl BuildNode {
+    pub(crate) fn aabb(&self) -> AABB {
+        match self {
+            BuildNode::Leaf { aabb, .. } => *aabb,
+            BuildNode::Interior { aabb, .. } => *aabb
+        }
+    }
+}
+
+pub(crate) struct BuildOutput {
+    pub(crate) root: BuildNode,
+    pub(crate) order: Vec<usize>,
+    pub(crate) sah_cost: f64,
+    pub(crate) node_count: usize,
+    pub(crate) leaf_count: usize,
+    pub(crate) max_depth: usize,
+    pub(crate) threads_used: usize
+}
+
+#[derive(Default)]
+struct BuildTally {
+    weighted_cost: f64,
+    node_count: usize,
+    leaf_count: usize,
+    max_depth: usize,
+    threads_used: usize
+}
+
+impl BuildTally {
+    fn merge(&mut self, other: BuildTally) {
+        self.weighted_cost += other.weighted_cost;
+        self.node_count += other.node_count;
+        self.leaf_count += other.leaf_count;
+        self.max_depth = usize::max(self.max_depth, other.max_depth);
+        self.threads_used += other.threads_used;
+    }
+}
+
+impl BVHBuilder {
+    pub fn new(time_0: f64, time_1: f64) -> Self {
+        Self {
+            time_0: time_0,
+            time_1: time_1,
+            bin_count: 16,
+            max_leaf_size: 4,
+            traversal_cost: 0.125,
+            intersection_cost: 1.0,
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get())
+        }
+    }
+
+    pub fn with_bin_count(self, bin_count: usize) -> Self {
+        Self {
+            bin_count: usize::max(bin_count, 2),
+            ..self
+        }
+    }
+
+    pub fn with_max_leaf_size(self, max_leaf_size: usize) -> Self {
+        Self {
+            max_leaf_size: usize::max(max_leaf_size, 1),
+            ..self
+        }
+    }
+
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: usize::max(threads, 1),
+            ..self
+        }
+    }
+
+    pub fn build(&self, hittable_list: &HittableList) -> BVHBuild {
+        let start = Instant::now();
+        let (output, unbounded) = self.build_nodes(&hittable_list.hittables);
+        let mut roots: Vec<Arc<dyn Hittable>> = unbounded.iter().map(|&index| hittable_list.hittables[index].clone()).collect();
+
+        let (sah_cost, node_count, leaf_count, max_depth, threads_used) = match output {
+            Some(output) => {
+                let objects: Vec<Arc<dyn Hittable>> = output.order.iter().map(|&index| hittable_list.hittables[index].clone()).collect();
+                roots.insert(0, Self::make_hittable(&objects, output.root));
+                (output.sah_cost, output.node_count, output.leaf_count, output.max_depth, output.threads_used)
+            },
+            None => (0.0, 0, 0, 0, 1)
+        };
+
+        let root: Arc<dyn Hittable> = match roots.len() {
+            1 => roots.pop().unwrap(),
+            _ => Arc::new(HittableList { hittables: roots })
+        };
+
+        BVHBuild {
+            root: root,
+            sah_cost: sah_cost,
+            node_count: node_count,
+            leaf_count: leaf_count,
+            max_depth: max_depth,
+            build_time: start.elapsed(),
+            threads_used: threads_used
+        }
+    }
+
+    pub(crate) fn build_nodes(&self, objects: &[Arc<dyn Hittable>]) -> (Option<BuildOutput>, Vec<usize>) {
+        assert!(!objects.is_empty(), "Empty HittableList passed into BVHBuilder.");
+
+        let bounds = self.bounds_in_parallel(objects);
+        let (bounded, unbounded): (Vec<usize>, Vec<usize>) = (0..objects.len()).partition(|&index| bounds[index].is_some());
+
+        if bounded.is_empty() {
+            return (None, unbounded)
+        }
+
+        let bounds: Vec<AABB> = bounded.iter().filter_map(|&index| bounds[index]).collect();
+        let output = self.build_from_bounds(&bounds);
+
+        let output = BuildOutput {
+            order: output.order.iter().map(|&index| bounded[index]).collect(),
+            ..output
+        };
+
+        (Some(output), unbounded)
+    }
+
+    pub(crate) fn build_from_bounds(&self, bounds: &[AABB]) -> BuildOutput {
+        assert!(!bounds.is_empty(), "Empty HittableList passed into BVHBuilder.");
+
+        let mut primitives: Vec<BuildPrimitive> = bounds.iter().enumerate().map(|(index, &aabb)| BuildPrimitive {
+            index: index,
+            aabb: aabb,
+            centroid: aabb.centroid()
+        }).collect();
+
+        let mut tally = BuildTally { threads_used: 1, ..Default::default() };
+        let root = self.build_recursive(&mut primitives, 0, 0, self.threads, &mut tally);
+        let root_area = root.aabb().surface_area();
+
+        let sah_cost = match root_area > 0.0 {
+            true => tally.weighted_cost / root_area,
+            false => tally.weighted_cost
+        };
+
+        BuildOutput {
+            root: root,
+            order: primitives.iter().map(|primitive| primitive.index).collect(),
+            sah_cost: sah_cost,
+            node_count: tally.node_count,
+            leaf_count: tally.leaf_count,
+            max_depth: tally.max_depth,
+            threads_used: tally.threads_used
+        }
+    }
+
+    fn bounds_in_parallel(&self, objects: &[Arc<dyn Hittable>]) -> Vec<Option<AABB>> {
+        let mut bounds = vec![None; objects.len()];
+        let chunk_size = match objects.len() < PARALLEL_THRESHOLD {
+            true => objects.len(),
+            false => objects.len().div_ceil(self.threads)
+        };
+
+        thread::scope(|scope| {
+            for (chunk, output) in bounds.chunks_mut(chunk_size).enumerate() {
+                let first = chunk * chunk_size;
+                let objects = &objects[first..(first + output.len())];
+
+                scope.spawn(move || {
+                    for (object, aabb) in objects.iter().zip(output.iter_mut()) {
+                        *aabb = object.bounding_box(self.time_0, self.time_1);
+                    }
+                });
+            }
+        });
+
+        bounds
+    }
+
+    fn build_recursive(&self, primitives: &mut [BuildPrimitive], offset: usize, depth: usize, threads: usize, tally: &mut BuildTally) -> BuildNode {
+        let aabb = Self::bounds_of(primitives);
+        let area = aabb.surface_area();
+
+        tally.node_count += 1;
+        tally.max_depth = usize::max(tally.max_depth, depth);
+
+        let leaf_cost = self.intersection_cost * primitives.len() as f64;
+        let centroid_bounds = Self::centroid_bounds_of(primitives);
+
+        let split = match depth < SAH_DEPTH {
+            true => self.find_split(primitives, &aabb, &centroid_bounds, threads),
+            false => None
+        };
+
+        let (axis, mid) = match split {
+            _ if depth >= MAX_DEPTH => (0, 0),
+            Some((axis, split, cost)) if primitives.len() > self.max_leaf_size || cost < leaf_cost => {
+                (axis, Self::partition(primitives, |primitive| self.bin_index(primitive, axis, &centroid_bounds) < split))
+            },
+            None if primitives.len() > self.max_leaf_size => (centroid_bounds.largest_axis(), primitives.len() / 2),
+            _ => (0, 0)
+        };
+
+        if mid == 0 || mid == primitives.len() {
+            tally.leaf_count += 1;
+            tally.weighted_cost += self.intersection_cost * primitives.len() as f64 * area;
+            return BuildNode::Leaf { aabb: aabb, first: offset, count: primitives.len() }
+        }
+
+        tally.weighted_cost += self.traversal_cost * area;
+
+        let (left, right) = primitives.split_at_mut(mid);
+
+        let parallel = threads > 1 && usize::min(left.len(), right.len()) >= PARALLEL_THRESHOLD;
+        let (left_threads, right_threads) = match parallel {
+            true => (threads / 2, threads - threads / 2),
+            false => (threads, threads)
+        };
+
+        let ((left, left_tally), right) = join(parallel, || {
+            let mut left_tally = BuildTally { threads_used: parallel as usize, ..Default::default() };
+            let node = self.build_recursive(left, offset, depth + 1, left_threads, &mut left_tally);
+            (node, left_tally)
+        }, || self.build_recursive(right, offset + mid, depth + 1, right_threads, tally));
+
+        tally.merge(left_tally);
+
+        BuildNode::Interior {
+            aabb: aabb,
+            axis: axis,
+            left: Box::new(left),
+            right: Box::new(right)
+        }
+    }
+
+    fn find_split(&self, primitives: &[BuildPrimitive], aabb: &AABB, centroid_bounds: &AABB, threads: usize) -> Option<(usize, usize, f64)> {
+        let area = aabb.surface_area();
+        let mut best: Option<(usize, usize, f64)> = None;
+
+        if primitives.len() < 2 {
+            return None
+        }
+
+        let axis_bins = self.bin_primitives(primitives, centroid_bounds, threads);
+
+        for (axis, bins) in axis_bins.iter().enumerate() {
+            if centroid_bounds.max[axis] <= centroid_bounds.min[axis] {
+                continue
+            }
+
+            let mut right_areas = vec![0.0; self.bin_count];
+            let mut right_counts = vec![0; self.bin_count];
+            let mut right: Option<AABB> = None;
+            let mut count = 0;
+            for i in (1..self.bin_count).rev() {
+                right = Self::merge(right, bins[i].aabb);
+                count += bins[i].count;
+                right_areas[i] = right.map_or(0.0, |aabb| aabb.surface_area());
+                right_counts[i] = count;
+            }
+
+            let mut left: Option<AABB> = None;
+            let mut count = 0;
+            for split in 1..self.bin_count {
+                left = Self::merge(left, bins[split - 1].aabb);
+                count += bins[split - 1].count;
+
+                if count == 0 || right_counts[split] == 0 {
+                    continue
+                }
+
+                let left_area = left.map_or(0.0, |aabb| aabb.surface_area());
+                let cost = match area > 0.0 {
+                    true => self.traversal_cost + self.intersection_cost * (count as f64 * left_area + right_counts[split] as f64 * right_areas[split]) / area,
+                    false => self.traversal_cost + self.intersection_cost * primitives.len() as f64
+                };
+
+                if best.is_none_or(|(_, _, best_cost)| cost < best_cost) {
+                    best = Some((axis, split, cost));
+                }
+            }
+        }
+
+        best
+    }
+
+    fn bin_primitives(&self, primitives: &[BuildPrimitive], centroid_bounds: &AABB, threads: usize) -> [Vec<Bin>; 3] {
+        if threads > 1 && primitives.len() >= 2 * PARALLEL_THRESHOLD {
+            let (left, right) = primitives.split_at(primitives.len() / 2);
+            let (mut bins, right_bins) = join(true, || self.bin_primitives(left, centroid_bounds, threads / 2), || self.bin_primitives(right, centroid_bounds, threads - threads / 2));
+
+            for (axis_bins, right_axis_bins) in bins.iter_mut().zip(right_bins) {
+                for (bin, right_bin) in axis_bins.iter_mut().zip(right_axis_bins) {
+                    bin.count += right_bin.count;
+                    bin.aabb = Self::merge(bin.aabb, right_bin.aabb);
+                }
+            }
+
+            return bins
+        }
+
+        let mut bins = [0, 1, 2].map(|_| vec![Bin { count: 0, aabb: None }; self.bin_count]);
+        for primitive in primitives {
+            for (axis, axis_bins) in bins.iter_mut().enumerate() {
+                let bin = &mut axis_bins[self.bin_index(primitive, axis, centroid_bounds)];
+                bin.count += 1;
+                bin.aabb = Self::merge(bin.aabb, Some(primitive.aabb));
+            }
+        }
+
+        bins
+    }
+
+    fn bin_index(&self, primitive: &BuildPrimitive, axis: usize, centroid_bounds: &AABB) -> usize {
+        let (min, max) = (centroid_bounds.min[axis], centroid_bounds.max[axis]);
+
+        if max <= min {
+            return 0
+        }
+
+        let offset = (primitive.centroid[axis] - min) / (max - min);
+        usize::min((offset * self.bin_count as f64) as usize, self.bin_count - 1)
+    }
+
+    fn centroid_bounds_of(primitives: &[BuildPrimitive]) -> AABB {
+        let mut aabb = AABB::new(primitives[0].centroid, primitives[0].centroid);
+
+        for primitive in &primitives[1..] {
+            aabb.encapsulate_point(primitive.centroid);
+        }
+
+        aabb
+    }
+
+    fn bounds_of(primitives: &[BuildPrimitive]) -> AABB {
+        let mut aabb = primitives[0].aabb;
+
+        for primitive in &primitives[1..] {
+            aabb.encapsulate(primitive.aabb);
+        }
+
+        aabb
+    }
+
+    fn merge(a: Option<AABB>, b: Option<AABB>) -> Option<AABB> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(a + b),
+            (Some(a), None) => Some(a),
+            (None, b) => b
+        }
+    }
+
+    fn partition<F: Fn(&BuildPrimitive) -> bool>(primitives: &mut [BuildPrimitive], predicate: F) -> usize {
+        let mut mid = 0;
+
+        for i in 0..primitives.len() {
+            if predicate(&primitives[i]) {
+                primitives.swap(i, mid);
+                mid += 1;
+            }
+        }
+
+        mid
+    }
+
+    fn make_hittable(objects: &[Arc<dyn Hittable>], node: BuildNode) -> Arc<dyn Hittable> {
+        match node {
+            BuildNode::Leaf { first, count: 1, .. } => objects[first].clone(),
+            BuildNode::Leaf { first, count, .. } => {
+                let mut leaf = HittableList::new();
+                for object in &objects[first..(first + count)] {
+                    leaf.add(object.clone());
+                }
+
+                Arc::new(leaf)
+            },
+            BuildNode::Interior { aabb, left, right, .. } => {
+                Arc::new(BVHNode {
+                    aabb: aabb,
+                    left: Self::make_hittable(objects, *left),
+                    right: Self::make_hittable(objects, *right)
+                })
+            }
+        }
+    }
+}
+
+fn join<A: Send, B, FA: FnOnce() -> A + Send, FB: FnOnce() -> B>(parallel: bool, a: FA, b: FB) -> (A, B) {
+    match parallel {
+        true => thread::scope(|scope| {
+            let a = scope.spawn(a);
+            let b = b();
+            (a.join().unwrap(), b)
+        }),
+        false => (a(), b())
+    }
+}
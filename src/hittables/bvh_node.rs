@@ -1,11 +1,14 @@
 use crate::structures::{AABB, HitRecord, Ray};
 use crate::hittables::{Hittable, HittableList};
+use crate::random::thread_rng;
+use crate::rendering::statistics;
 
 use std::sync::Arc;
+use std::mem;
 use std::cmp::Ordering;
 use std::cmp::Ordering::Less;
 
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
 pub struct BVHNode {
     pub aabb: AABB,
@@ -15,15 +18,13 @@ pub struct BVHNode {
 
 impl BVHNode {
     pub fn new(hittable_list: &HittableList, time_0: f64, time_1: f64) -> Self {
-        let objects: Vec<Arc<dyn Hittable>> = hittable_list.hittables[0..hittable_list.hittables.len()].iter().cloned().collect();
-        Self::internal_new(&objects, time_0, time_1)
+        let mut objects = hittable_list.hittables.clone();
+        Self::internal_new(&mut objects, time_0, time_1)
     }
 
-    fn internal_new(objects: &Vec<Arc<dyn Hittable>>, time_0: f64, time_1: f64) -> Self {
+    fn internal_new(objects: &mut [Arc<dyn Hittable>], time_0: f64, time_1: f64) -> Self {
         let mut rng = thread_rng();
 
-        let mut objects = objects.clone();
-
         let axis = rng.gen_range(0..3);
         let comparator = |a: &Arc<dyn Hittable>, b: &Arc<dyn Hittable>| BVHNode::box_compare(a, b, axis, time_0, time_1);
 
@@ -44,10 +45,9 @@ impl BVHNode {
         } else {
             objects.sort_by(comparator);
             let mid = objects.len() / 2;
-            let l_vec: Vec<Arc<dyn Hittable>> = objects[0..mid].iter().cloned().collect();
-            let r_vec: Vec<Arc<dyn Hittable>> = objects[mid..objects.len()].iter().cloned().collect();
-            left = Arc::new(Self::internal_new(&l_vec, time_0, time_1));
-            right = Arc::new(Self::internal_new(&r_vec, time_0, time_1));
+            let (l_objects, r_objects) = objects.split_at_mut(mid);
+            left = Arc::new(Self::internal_new(l_objects, time_0, time_1));
+            right = Arc::new(Self::internal_new(r_objects, time_0, time_1));
         }
 
         let aabb = match (left.bounding_box(time_0, time_1), right.bounding_box(time_0, time_1)) {
@@ -73,10 +73,16 @@ impl BVHNode {
 impl Hittable for BVHNode {
     
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        statistics::record_bvh_node_test();
+
         if !self.aabb.hit(*ray, t_min, t_max) {
             return None
         }
 
+        if Arc::ptr_eq(&self.left, &self.right) {
+            return self.left.hit(ray, t_min, t_max)
+        }
+
         match self.left.hit(ray, t_min, t_max) {
             Some(record) => match self.right.hit(ray, t_min, record.t) {
                 Some(hit) => Some(hit),
@@ -86,7 +92,26 @@ impl Hittable for BVHNode {
         }
     }
 
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        statistics::record_bvh_node_test();
+
+        if !self.aabb.hit(*ray, t_min, t_max) {
+            return false
+        }
+
+        self.left.occluded(ray, t_min, t_max) || (!Arc::ptr_eq(&self.left, &self.right) && self.right.occluded(ray, t_min, t_max))
+    }
+
     fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
         Some(self.aabb)
     }
+
+    fn acceleration_memory(&self) -> usize {
+        let children = match Arc::ptr_eq(&self.left, &self.right) {
+            true => self.left.acceleration_memory(),
+            false => self.left.acceleration_memory() + self.right.acceleration_memory()
+        };
+
+        mem::size_of::<Self>() + children
+    }
 }
\ No newline at end of file
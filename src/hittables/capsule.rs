@@ -0,0 +1,122 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+use crate::utility::solve_quadratic;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+pub struct Capsule {
+    pub center: Point3,
+    pub radius: f64,
+    pub height: f64,
+    pub material: Arc<dyn Material>
+}
+
+impl Capsule {
+    pub fn new(center: Point3, radius: f64, height: f64, material: Arc<dyn Material>) -> Self {
+        Self {
+            center: center,
+            radius: radius,
+            height: height,
+            material: material
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        2.0 * PI * self.radius * self.height + 4.0 * PI * self.radius.powi(2)
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        match rng.gen_range(0.0..self.area()) < 2.0 * PI * self.radius * self.height {
+            true => {
+                let phi = rng.gen_range(0.0..(2.0 * PI));
+                let normal = Vec3::new(phi.cos(), 0.0, phi.sin());
+                (self.center + self.radius * normal + Vec3::new(0.0, rng.gen_range(0.0..=self.height), 0.0), normal)
+            },
+            false => {
+                let normal = Vec3::random_in_unit_sphere().normalized();
+                let cap = match normal.y < 0.0 {
+                    true => 0.0,
+                    false => self.height
+                };
+
+                (self.center + Vec3::new(0.0, cap, 0.0) + self.radius * normal, normal)
+            }
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<f64> {
+        statistics::record_primitive_test();
+
+        let p = ray.origin - self.center;
+        let d = ray.direction;
+        let mut closest: Option<f64> = None;
+        let mut t_max = t_max;
+
+        let a = d.x * d.x + d.z * d.z;
+        let b = 2.0 * (p.x * d.x + p.z * d.z);
+        let c = p.x * p.x + p.z * p.z - self.radius.powi(2);
+
+        for t in solve_quadratic(a, b, c) {
+            let y = p.y + t * d.y;
+            if t_min < t && t < t_max && 0.0 <= y && y <= self.height {
+                closest = Some(t);
+                t_max = t;
+                break
+            }
+        }
+
+        for &cap in &[0.0, self.height] {
+            let oc = p - Vec3::new(0.0, cap, 0.0);
+
+            for t in solve_quadratic(d.squared_length(), 2.0 * Vec3::dot(&oc, &d), oc.squared_length() - self.radius.powi(2)) {
+                let y = p.y + t * d.y;
+                let outside_body = match cap == 0.0 {
+                    true => y <= 0.0,
+                    false => y >= self.height
+                };
+
+                if t_min < t && t < t_max && outside_body {
+                    closest = Some(t);
+                    t_max = t;
+                    break
+                }
+            }
+        }
+
+        closest
+    }
+}
+
+impl Hittable for Capsule {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let t = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let p = point - self.center;
+
+        let axis = Vec3::new(0.0, f64::clamp(p.y, 0.0, self.height), 0.0);
+        let normal = p - axis;
+
+        let u = (f64::atan2(-p.z, p.x) + PI) / (2.0 * PI);
+        let v = (p.y + self.radius) / (self.height + 2.0 * self.radius);
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let min = self.center - Vec3::new(self.radius, self.radius, self.radius);
+        let max = self.center + Vec3::new(self.radius, self.height + self.radius, self.radius);
+        Some(AABB::new(min, max))
+    }
+}
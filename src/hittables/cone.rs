@@ -0,0 +1,128 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+use crate::utility::solve_quadratic;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+#[derive(Clone, Copy)]
+enum Part {
+    Body,
+    Base
+}
+
+pub struct Cone {
+    pub center: Point3,
+    pub radius: f64,
+    pub height: f64,
+    pub capped: bool,
+    pub material: Arc<dyn Material>
+}
+
+impl Cone {
+    pub fn new(center: Point3, radius: f64, height: f64, capped: bool, material: Arc<dyn Material>) -> Self {
+        Self {
+            center: center,
+            radius: radius,
+            height: height,
+            capped: capped,
+            material: material
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        let base = match self.capped {
+            true => PI * self.radius.powi(2),
+            false => 0.0
+        };
+
+        PI * self.radius * self.radius.hypot(self.height) + base
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        let phi = rng.gen_range(0.0..(2.0 * PI));
+        let q = rng.gen::<f64>().sqrt();
+        let side = PI * self.radius * self.radius.hypot(self.height);
+
+        match rng.gen_range(0.0..self.area()) < side {
+            true => {
+                let normal = Vec3::new(self.height * phi.cos(), self.radius, self.height * phi.sin()).normalized();
+                (self.center + Vec3::new(self.radius * q * phi.cos(), self.height * (1.0 - q), self.radius * q * phi.sin()), normal)
+            },
+            false => (self.center + Vec3::new(self.radius * q * phi.cos(), 0.0, self.radius * q * phi.sin()), Vec3::down())
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, Part)> {
+        statistics::record_primitive_test();
+
+        let p = ray.origin - self.center;
+        let d = ray.direction;
+        let k = (self.radius / self.height).powi(2);
+        let h = self.height - p.y;
+
+        let mut closest: Option<(f64, Part)> = None;
+        let mut t_max = t_max;
+
+        let a = d.x * d.x + d.z * d.z - k * d.y * d.y;
+        let b = 2.0 * (p.x * d.x + p.z * d.z + k * h * d.y);
+        let c = p.x * p.x + p.z * p.z - k * h * h;
+
+        for t in solve_quadratic(a, b, c) {
+            let y = p.y + t * d.y;
+            if t_min < t && t < t_max && 0.0 <= y && y <= self.height {
+                closest = Some((t, Part::Body));
+                t_max = t;
+                break
+            }
+        }
+
+        if self.capped && d.y.abs() > 1e-12 {
+            let t = -p.y / d.y;
+            let x = p.x + t * d.x;
+            let z = p.z + t * d.z;
+
+            if t_min < t && t < t_max && x * x + z * z <= self.radius.powi(2) {
+                closest = Some((t, Part::Base));
+            }
+        }
+
+        closest
+    }
+}
+
+impl Hittable for Cone {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, part) = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let p = point - self.center;
+
+        let u = (f64::atan2(-p.z, p.x) + PI) / (2.0 * PI);
+        let (normal, v) = match part {
+            Part::Body => {
+                let k = (self.radius / self.height).powi(2);
+                (Vec3::new(p.x, k * (self.height - p.y), p.z), p.y / self.height)
+            },
+            Part::Base => (Vec3::down(), f64::sqrt(p.x * p.x + p.z * p.z) / self.radius)
+        };
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let min = self.center - Vec3::new(self.radius, 0.0, self.radius);
+        let max = self.center + Vec3::new(self.radius, self.height, self.radius);
+        Some(AABB::new(min, max))
+    }
+}
@@ -3,9 +3,10 @@ use crate::hittables::Hittable;
 use crate::textures::Texture;
 use crate::materials::Material;
 use crate::materials::volumetric::Isotropic;
+use crate::random::thread_rng;
 
 use std::sync::Arc;
-use rand::{ thread_rng, Rng };
+use rand::Rng;
 
 pub struct ConstantMedium {
     pub boundary: Arc<dyn Hittable>,
@@ -63,6 +64,10 @@ impl Hittable for ConstantMedium {
     fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> { 
         self.boundary.bounding_box(time_0, time_1)
     }
+
+    fn acceleration_memory(&self) -> usize {
+        self.boundary.acceleration_memory()
+    }
 }
 
 impl ConstantMedium {
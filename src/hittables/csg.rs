@@ -0,0 +1,134 @@
+use crate::structures::{Ray, HitRecord, AABB, Vec3};
+use crate::hittables::Hittable;
+
+use std::sync::Arc;
+
+const TANGENT_EPSILON: f64 = 1e-6;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum CSGOperation {
+    Union,
+    Intersection,
+    Difference
+}
+
+impl CSGOperation {
+    fn contains(&self, inside_left: bool, inside_right: bool) -> bool {
+        match self {
+            CSGOperation::Union => inside_left || inside_right,
+            CSGOperation::Intersection => inside_left && inside_right,
+            CSGOperation::Difference => inside_left && !inside_right
+        }
+    }
+}
+
+pub struct CSG {
+    pub operation: CSGOperation,
+    pub left: Arc<dyn Hittable>,
+    pub right: Arc<dyn Hittable>
+}
+
+impl CSG {
+    pub fn new(operation: CSGOperation, left: Arc<dyn Hittable>, right: Arc<dyn Hittable>) -> Self {
+        Self {
+            operation: operation,
+            left: left,
+            right: right
+        }
+    }
+
+    pub fn union(left: Arc<dyn Hittable>, right: Arc<dyn Hittable>) -> Self {
+        Self::new(CSGOperation::Union, left, right)
+    }
+
+    pub fn intersection(left: Arc<dyn Hittable>, right: Arc<dyn Hittable>) -> Self {
+        Self::new(CSGOperation::Intersection, left, right)
+    }
+
+    pub fn difference(left: Arc<dyn Hittable>, right: Arc<dyn Hittable>) -> Self {
+        Self::new(CSGOperation::Difference, left, right)
+    }
+
+    fn boundaries(&self, ray: &Ray) -> Vec<HitRecord> {
+        let left = Self::crossings(self.left.as_ref(), ray);
+        let right = Self::crossings(self.right.as_ref(), ray);
+
+        let mut events: Vec<(HitRecord, bool)> = left.into_iter().map(|hit| (hit, true)).chain(right.into_iter().map(|hit| (hit, false))).collect();
+        events.sort_by(|(a, _), (b, _)| a.t.partial_cmp(&b.t).unwrap());
+
+        let mut inside_left = false;
+        let mut inside_right = false;
+        let mut inside = false;
+        let mut boundaries = Vec::new();
+
+        for (mut hit, is_left) in events {
+            match is_left {
+                true => inside_left = !inside_left,
+                false => inside_right = !inside_right
+            }
+
+            if self.operation.contains(inside_left, inside_right) != inside {
+                inside = !inside;
+
+                if !is_left && self.operation == CSGOperation::Difference {
+                    hit.normal = -hit.normal;
+                }
+
+                boundaries.push(hit);
+            }
+        }
+
+        boundaries
+    }
+
+    fn crossings(hittable: &dyn Hittable, ray: &Ray) -> Vec<HitRecord> {
+        let mut hits = hittable.hit_all(ray, f64::NEG_INFINITY, f64::INFINITY);
+
+        if hits.len() % 2 == 1 {
+            let direction = ray.direction.normalized();
+            let grazing = (0..hits.len()).min_by(|&a, &b| {
+                let a = Vec3::dot(&hits[a].normal, &direction).abs();
+                let b = Vec3::dot(&hits[b].normal, &direction).abs();
+                a.partial_cmp(&b).unwrap()
+            });
+
+            if let Some(index) = grazing.filter(|&index| Vec3::dot(&hits[index].normal, &direction).abs() < TANGENT_EPSILON) {
+                hits.remove(index);
+            }
+        }
+
+        hits
+    }
+}
+
+impl Hittable for CSG {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.boundaries(ray).into_iter().find(|hit| t_min < hit.t && hit.t < t_max)
+    }
+
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        self.boundaries(ray).into_iter().filter(|hit| t_min < hit.t && hit.t < t_max).collect()
+    }
+
+    fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
+        let left = self.left.bounding_box(time_0, time_1)?;
+        let right = self.right.bounding_box(time_0, time_1)?;
+
+        match self.operation {
+            CSGOperation::Union => {
+                let mut aabb = left;
+                aabb.encapsulate(right);
+                Some(aabb)
+            },
+            CSGOperation::Intersection => {
+                let mut aabb = left;
+                for i in 0..3 {
+                    aabb.min[i] = f64::max(left.min[i], right.min[i]);
+                    aabb.max[i] = f64::max(f64::min(left.max[i], right.max[i]), aabb.min[i]);
+                }
+                Some(aabb)
+            },
+            CSGOperation::Difference => Some(left)
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::rendering::statistics;
+
+use std::sync::Arc;
+
+const MAX_DEPTH: i32 = 10;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum CurveType {
+    Ribbon,
+    Round
+}
+
+#[derive(Clone)]
+pub struct Curve {
+    pub control_points: [Point3; 4],
+    pub width_0: f64,
+    pub width_1: f64,
+    pub curve_type: CurveType,
+    pub material: Arc<dyn Material>,
+    pub u_min: f64,
+    pub u_max: f64
+}
+
+struct Frame {
+    origin: Point3,
+    x: Vec3,
+    y: Vec3,
+    z: Vec3
+}
+
+impl Frame {
+    fn new(ray: &Ray) -> Self {
+        let z = ray.direction.normalized();
+        let helper = match z.x.abs() > 0.9 {
+            true => Vec3::up(),
+            false => Vec3::right()
+        };
+        let x = Vec3::cross(&helper, &z).normalized();
+
+        Self {
+            origin: ray.origin,
+            x: x,
+            y: Vec3::cross(&z, &x),
+            z: z
+        }
+    }
+
+    fn to_local(&self, point: Point3) -> Point3 {
+        let p = point - self.origin;
+        Point3::new(Vec3::dot(&p, &self.x), Vec3::dot(&p, &self.y), Vec3::dot(&p, &self.z))
+    }
+}
+
+impl Curve {
+    pub fn new(control_points: [Point3; 4], width_0: f64, width_1: f64, curve_type: CurveType, material: Arc<dyn Material>) -> Self {
+        Self {
+            control_points: control_points,
+            width_0: width_0,
+            width_1: width_1,
+            curve_type: curve_type,
+            material: material,
+            u_min: 0.0,
+            u_max: 1.0
+        }
+    }
+
+    pub fn ribbon(control_points: [Point3; 4], width_0: f64, width_1: f64, material: Arc<dyn Material>) -> Self {
+        Self::new(control_points, width_0, width_1, CurveType::Ribbon, material)
+    }
+
+    pub fn round(control_points: [Point3; 4], width_0: f64, width_1: f64, material: Arc<dyn Material>) -> Self {
+        Self::new(control_points, width_0, width_1, CurveType::Round, material)
+    }
+
+    pub fn split(&self, segments: usize) -> Vec<Curve> {
+        assert!(segments > 0, "Curve needs at least one segment.");
+
+        (0..segments).map(|segment| {
+            let u_min = self.u_min + (self.u_max - self.u_min) * segment as f64 / segments as f64;
+            let u_max = self.u_min + (self.u_max - self.u_min) * (segment + 1) as f64 / segments as f64;
+
+            Self {
+                u_min: u_min,
+                u_max: u_max,
+                ..self.clone()
+            }
+        }).collect()
+    }
+
+    pub fn point_at(&self, u: f64) -> Point3 {
+        Self::blossom(&self.control_points, u, u, u)
+    }
+
+    pub fn tangent_at(&self, u: f64) -> Vec3 {
+        let [p0, p1, p2, p3] = self.control_points;
+        let derivative = 3.0 * (1.0 - u).powi(2) * (p1 - p0) + 6.0 * (1.0 - u) * u * (p2 - p1) + 3.0 * u * u * (p3 - p2);
+
+        match derivative.squared_length() > 0.0 {
+            true => derivative.normalized(),
+            false => (p3 - p0).normalized()
+        }
+    }
+
+    pub fn width_at(&self, u: f64) -> f64 {
+        self.width_0 + (self.width_1 - self.width_0) * u
+    }
+
+    fn blossom(points: &[Point3; 4], u_0: f64, u_1: f64, u_2: f64) -> Point3 {
+        let a = [Vec3::lerp(&points[0], &points[1], u_0), Vec3::lerp(&points[1], &points[2], u_0), Vec3::lerp(&points[2], &points[3], u_0)];
+        let b = [Vec3::lerp(&a[0], &a[1], u_1), Vec3::lerp(&a[1], &a[2], u_1)];
+        Vec3::lerp(&b[0], &b[1], u_2)
+    }
+
+    fn segment_points(&self) -> [Point3; 4] {
+        let (u_0, u_1) = (self.u_min, self.u_max);
+        [
+            Self::blossom(&self.control_points, u_0, u_0, u_0),
+            Self::blossom(&self.control_points, u_0, u_0, u_1),
+            Self::blossom(&self.control_points, u_0, u_1, u_1),
+            Self::blossom(&self.control_points, u_1, u_1, u_1)
+        ]
+    }
+
+    fn split_half(points: &[Point3; 4]) -> ([Point3; 4], [Point3; 4]) {
+        let [p0, p1, p2, p3] = *points;
+        let middle = (p0 + 3.0 * p1 + 3.0 * p2 + p3) / 8.0;
+
+        (
+            [p0, (p0 + p1) / 2.0, (p0 + 2.0 * p1 + p2) / 4.0, middle],
+            [middle, (p1 + 2.0 * p2 + p3) / 4.0, (p2 + p3) / 2.0, p3]
+        )
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, f64)> {
+        statistics::record_primitive_test();
+
+        let frame = Frame::new(ray);
+        let points = self.segment_points().map(|point| frame.to_local(point));
+
+        let curvature = points.windows(3).fold(0.0, |curvature: f64, window| {
+            let second = window[0] - 2.0 * window[1] + window[2];
+            curvature.max(second.x.abs()).max(second.y.abs()).max(second.z.abs())
+        });
+
+        let epsilon = f64::max(self.width_0, self.width_1) * 0.05;
+        let depth = match curvature > 0.0 && epsilon > 0.0 {
+            true => i32::clamp((f64::log2(std::f64::consts::SQRT_2 * 6.0 * curvature / (8.0 * epsilon)) / 2.0) as i32, 0, MAX_DEPTH),
+            false => 0
+        };
+
+        let speed = ray.direction.length();
+        let mut z_max = t_max * speed;
+        let mut closest = None;
+
+        self.recursive_intersect(&points, (self.u_min, self.u_max), depth, t_min * speed, &mut z_max, &mut closest);
+
+        closest.map(|(z, u)| (z / speed, u))
+    }
+
+    fn recursive_intersect(&self, points: &[Point3; 4], (u_0, u_1): (f64, f64), depth: i32, z_min: f64, z_max: &mut f64, closest: &mut Option<(f64, f64)>) {
+        let half_width = 0.5 * f64::max(self.width_at(u_0), self.width_at(u_1));
+
+        for axis in 0..3 {
+            let low = points.iter().map(|point| point[axis]).fold(f64::INFINITY, f64::min) - half_width;
+            let high = points.iter().map(|point| point[axis]).fold(f64::NEG_INFINITY, f64::max) + half_width;
+
+            let outside = match axis {
+                2 => high < z_min || low > *z_max,
+                _ => high < 0.0 || low > 0.0
+            };
+
+            if outside {
+                return
+            }
+        }
+
+        if depth > 0 {
+            let middle = 0.5 * (u_0 + u_1);
+            let (left, right) = Self::split_half(points);
+
+            self.recursive_intersect(&left, (u_0, middle), depth - 1, z_min, z_max, closest);
+            self.recursive_intersect(&right, (middle, u_1), depth - 1, z_min, z_max, closest);
+            return
+        }
+
+        let [p0, p1, p2, p3] = *points;
+
+        if (p1.y - p0.y) * -p0.y + p0.x * (p0.x - p1.x) < 0.0 {
+            return
+        }
+
+        if (p2.y - p3.y) * -p3.y + p3.x * (p3.x - p2.x) < 0.0 {
+            return
+        }
+
+        let (segment_x, segment_y) = (p3.x - p0.x, p3.y - p0.y);
+        let denominator = segment_x * segment_x + segment_y * segment_y;
+
+        if denominator == 0.0 {
+            return
+        }
+
+        let w = f64::clamp((-p0.x * segment_x - p0.y * segment_y) / denominator, 0.0, 1.0);
+        let u = u_0 + (u_1 - u_0) * w;
+        let hit_width = self.width_at(u);
+        let center = Self::blossom(points, w, w, w);
+
+        if center.x * center.x + center.y * center.y > 0.25 * hit_width * hit_width {
+            return
+        }
+
+        if center.z <= z_min || center.z >= *z_max {
+            return
+        }
+
+        *z_max = center.z;
+        *closest = Some((center.z, u));
+    }
+}
+
+impl Hittable for Curve {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, u) = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let tangent = self.tangent_at(u);
+
+        let direction = ray.direction.normalized();
+        let facing = -(direction - Vec3::dot(&direction, &tangent) * tangent);
+        let facing = match facing.squared_length() > 0.0 {
+            true => facing.normalized(),
+            false => Vec3::cross(&tangent, &Vec3::right()).normalized()
+        };
+        let side = Vec3::cross(&tangent, &facing);
+
+        let offset = f64::clamp(Vec3::dot(&(point - self.point_at(u)), &side) / (0.5 * self.width_at(u)), -1.0, 1.0);
+        let normal = match self.curve_type {
+            CurveType::Ribbon => facing,
+            CurveType::Round => facing * f64::sqrt(1.0 - offset * offset) + side * offset
+        };
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, 0.5 + 0.5 * offset).with_tangent(tangent))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let half_width = 0.5 * f64::max(self.width_at(self.u_min), self.width_at(self.u_max));
+        let padding = Vec3::new(half_width, half_width, half_width);
+        let aabb = AABB::from_points(&self.segment_points().to_vec());
+
+        Some(AABB::new(aabb.min - padding, aabb.max + padding))
+    }
+}
@@ -0,0 +1,133 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+use crate::utility::solve_quadratic;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+#[derive(Clone, Copy)]
+enum Part {
+    Body,
+    Bottom,
+    Top
+}
+
+pub struct Cylinder {
+    pub center: Point3,
+    pub radius: f64,
+    pub height: f64,
+    pub capped: bool,
+    pub material: Arc<dyn Material>
+}
+
+impl Cylinder {
+    pub fn new(center: Point3, radius: f64, height: f64, capped: bool, material: Arc<dyn Material>) -> Self {
+        Self {
+            center: center,
+            radius: radius,
+            height: height,
+            capped: capped,
+            material: material
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        let caps = match self.capped {
+            true => 2.0 * PI * self.radius.powi(2),
+            false => 0.0
+        };
+
+        2.0 * PI * self.radius * self.height + caps
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        let phi = rng.gen_range(0.0..(2.0 * PI));
+        let side = 2.0 * PI * self.radius * self.height;
+
+        match rng.gen_range(0.0..self.area()) {
+            choice if choice < side => {
+                let normal = Vec3::new(phi.cos(), 0.0, phi.sin());
+                (self.center + self.radius * normal + Vec3::new(0.0, rng.gen_range(0.0..=self.height), 0.0), normal)
+            },
+            choice => {
+                let r = self.radius * rng.gen::<f64>().sqrt();
+                let (y, normal) = match choice < side + PI * self.radius.powi(2) {
+                    true => (0.0, Vec3::down()),
+                    false => (self.height, Vec3::up())
+                };
+                (self.center + Vec3::new(r * phi.cos(), y, r * phi.sin()), normal)
+            }
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, Part)> {
+        statistics::record_primitive_test();
+
+        let p = ray.origin - self.center;
+        let d = ray.direction;
+        let mut closest: Option<(f64, Part)> = None;
+        let mut t_max = t_max;
+
+        let a = d.x * d.x + d.z * d.z;
+        let b = 2.0 * (p.x * d.x + p.z * d.z);
+        let c = p.x * p.x + p.z * p.z - self.radius.powi(2);
+
+        for t in solve_quadratic(a, b, c) {
+            let y = p.y + t * d.y;
+            if t_min < t && t < t_max && 0.0 <= y && y <= self.height {
+                closest = Some((t, Part::Body));
+                t_max = t;
+                break
+            }
+        }
+
+        if self.capped && d.y.abs() > 1e-12 {
+            for &(y, part) in &[(0.0, Part::Bottom), (self.height, Part::Top)] {
+                let t = (y - p.y) / d.y;
+                let x = p.x + t * d.x;
+                let z = p.z + t * d.z;
+
+                if t_min < t && t < t_max && x * x + z * z <= self.radius.powi(2) {
+                    closest = Some((t, part));
+                    t_max = t;
+                }
+            }
+        }
+
+        closest
+    }
+}
+
+impl Hittable for Cylinder {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, part) = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let p = point - self.center;
+
+        let u = (f64::atan2(-p.z, p.x) + PI) / (2.0 * PI);
+        let (normal, v) = match part {
+            Part::Body => (Vec3::new(p.x, 0.0, p.z), p.y / self.height),
+            Part::Bottom => (Vec3::down(), f64::sqrt(p.x * p.x + p.z * p.z) / self.radius),
+            Part::Top => (Vec3::up(), f64::sqrt(p.x * p.x + p.z * p.z) / self.radius)
+        };
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let min = self.center - Vec3::new(self.radius, 0.0, self.radius);
+        let max = self.center + Vec3::new(self.radius, self.height, self.radius);
+        Some(AABB::new(min, max))
+    }
+}
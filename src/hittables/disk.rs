@@ -0,0 +1,89 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+pub struct Disk {
+    pub center: Point3,
+    pub radius: f64,
+    pub inner_radius: f64,
+    pub material: Arc<dyn Material>
+}
+
+impl Disk {
+    pub fn new(center: Point3, radius: f64, material: Arc<dyn Material>) -> Self {
+        Self::annulus(center, radius, 0.0, material)
+    }
+
+    pub fn annulus(center: Point3, radius: f64, inner_radius: f64, material: Arc<dyn Material>) -> Self {
+        Self {
+            center: center,
+            radius: radius,
+            inner_radius: inner_radius,
+            material: material
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        PI * (self.radius.powi(2) - self.inner_radius.powi(2))
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        let r = f64::sqrt(rng.gen_range(self.inner_radius.powi(2)..=self.radius.powi(2)));
+        let phi = rng.gen_range(0.0..(2.0 * PI));
+
+        (self.center + Vec3::new(r * phi.cos(), 0.0, r * phi.sin()), Vec3::up())
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<f64> {
+        statistics::record_primitive_test();
+
+        if ray.direction.y.abs() < 1e-12 {
+            return None
+        }
+
+        let t = (self.center.y - ray.origin.y) / ray.direction.y;
+
+        if t <= t_min || t >= t_max {
+            return None
+        }
+
+        let p = ray.at(t) - self.center;
+        let squared_radius = p.x * p.x + p.z * p.z;
+
+        match self.inner_radius.powi(2) <= squared_radius && squared_radius <= self.radius.powi(2) {
+            true => Some(t),
+            false => None
+        }
+    }
+}
+
+impl Hittable for Disk {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let t = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let p = point - self.center;
+
+        let u = (f64::atan2(-p.z, p.x) + PI) / (2.0 * PI);
+        let v = f64::sqrt(p.x * p.x + p.z * p.z) / self.radius;
+
+        Some(HitRecord::new(point, Vec3::up(), self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let extent = Vec3::new(self.radius, 0.0001, self.radius);
+        Some(AABB::new(self.center - extent, self.center + extent))
+    }
+}
@@ -0,0 +1,110 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::rendering::statistics;
+use crate::sdf::SDF;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+pub struct DistanceField {
+    pub sdf: Arc<dyn SDF>,
+    pub bounds: AABB,
+    pub material: Arc<dyn Material>,
+    pub max_steps: u32,
+    pub epsilon: f64,
+    pub step_scale: f64
+}
+
+impl DistanceField {
+    pub fn new(sdf: Arc<dyn SDF>, bounds: AABB, material: Arc<dyn Material>) -> Self {
+        Self {
+            sdf: sdf,
+            bounds: bounds,
+            material: material,
+            max_steps: 256,
+            epsilon: 1e-5,
+            step_scale: 1.0
+        }
+    }
+
+    pub fn with_max_steps(self, max_steps: u32) -> Self {
+        Self {
+            max_steps: max_steps,
+            ..self
+        }
+    }
+
+    pub fn with_epsilon(self, epsilon: f64) -> Self {
+        Self {
+            epsilon: epsilon,
+            ..self
+        }
+    }
+
+    pub fn with_step_scale(self, step_scale: f64) -> Self {
+        Self {
+            step_scale: step_scale,
+            ..self
+        }
+    }
+
+    pub fn normal(&self, point: Point3) -> Vec3 {
+        let h = self.epsilon;
+        let offsets = [Vec3::new(1.0, -1.0, -1.0), Vec3::new(-1.0, -1.0, 1.0), Vec3::new(-1.0, 1.0, -1.0), Vec3::new(1.0, 1.0, 1.0)];
+
+        offsets.iter().fold(Vec3::zero(), |normal, &offset| normal + offset * self.sdf.distance(point + offset * h))
+    }
+
+    fn march(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<f64> {
+        statistics::record_primitive_test();
+
+        let (start, end) = self.bounds.hit_interval(ray, t_min, t_max)?;
+        let speed = ray.direction.length();
+        let sign = f64::signum(self.sdf.distance(ray.at(start)));
+
+        let mut t = start;
+
+        for _ in 0..self.max_steps {
+            let distance = sign * self.sdf.distance(ray.at(t));
+
+            if distance < self.epsilon {
+                let ahead = sign * self.sdf.distance(ray.at(t + self.epsilon / speed));
+
+                match ahead < distance && t > t_min {
+                    true => return Some(t),
+                    false => t += 2.0 * self.epsilon / speed
+                }
+            } else {
+                t += self.step_scale * distance / speed;
+            }
+
+            if t > end {
+                return None
+            }
+        }
+
+        None
+    }
+}
+
+impl Hittable for DistanceField {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let t = self.march(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let normal = self.normal(point).normalized();
+
+        let u = (f64::atan2(-normal.z, normal.x) + PI) / (2.0 * PI);
+        let v = f64::acos(f64::clamp(-normal.y, -1.0, 1.0)) / PI;
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.march(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        Some(self.bounds)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::structures::{AABB, HitRecord, Ray};
+use crate::hittables::{Hittable, HittableList, BVHBuilder};
+use crate::hittables::linear_bvh::LinearBVH;
+
+use std::sync::Arc;
+use std::mem;
+use std::time::{Duration, Instant};
+
+pub struct FlatBVH {
+    pub sah_cost: f64,
+    pub build_time: Duration,
+    bvh: Option<LinearBVH>,
+    primitives: Vec<Arc<dyn Hittable>>,
+    unbounded: Vec<Arc<dyn Hittable>>
+}
+
+impl FlatBVH {
+    pub fn new(hittable_list: &HittableList, time_0: f64, time_1: f64) -> Self {
+        Self::with_builder(hittable_list, &BVHBuilder::new(time_0, time_1))
+    }
+
+    pub fn with_builder(hittable_list: &HittableList, builder: &BVHBuilder) -> Self {
+        let builder = builder.with_max_leaf_size(usize::min(builder.max_leaf_size, u16::MAX as usize));
+        let start = Instant::now();
+        let (output, unbounded) = builder.build_nodes(&hittable_list.hittables);
+        let unbounded = unbounded.iter().map(|&index| hittable_list.hittables[index].clone()).collect();
+
+        let (sah_cost, bvh, primitives) = match output {
+            Some(output) => {
+                let primitives = output.order.iter().map(|&index| hittable_list.hittables[index].clone()).collect();
+                (output.sah_cost, Some(LinearBVH::new(output.root, output.node_count)), primitives)
+            },
+            None => (0.0, None, vec![])
+        };
+
+        Self {
+            sah_cost: sah_cost,
+            build_time: start.elapsed(),
+            bvh: bvh,
+            primitives: primitives,
+            unbounded: unbounded
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.bvh.as_ref().map_or(0, |bvh| bvh.node_count())
+    }
+
+    pub fn primitive_count(&self) -> usize {
+        self.primitives.len() + self.unbounded.len()
+    }
+
+    pub fn unbounded_count(&self) -> usize {
+        self.unbounded.len()
+    }
+}
+
+impl Hittable for FlatBVH {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut closest_hit = self.bvh.as_ref().and_then(|bvh| bvh.hit(ray, t_min, t_max, |index, t_min, t_max| self.primitives[index].hit(ray, t_min, t_max)));
+
+        for primitive in &self.unbounded {
+            let closest_so_far = closest_hit.as_ref().map_or(t_max, |hit| hit.t);
+            if let Some(record) = primitive.hit(ray, t_min, closest_so_far) {
+                closest_hit = Some(record);
+            }
+        }
+
+        closest_hit
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.unbounded.iter().any(|primitive| primitive.occluded(ray, t_min, t_max)) ||
+        self.bvh.as_ref().is_some_and(|bvh| bvh.occluded(ray, t_min, t_max, |index| self.primitives[index].occluded(ray, t_min, t_max)))
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        match self.unbounded.is_empty() {
+            true => self.bvh.as_ref().map(|bvh| bvh.aabb()),
+            false => None
+        }
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        let bvh = self.bvh.as_ref().map_or(0, |bvh| bvh.memory());
+        let own = mem::size_of::<Self>() + bvh + (self.primitives.capacity() + self.unbounded.capacity()) * mem::size_of::<Arc<dyn Hittable>>();
+        own + self.primitives.iter().chain(&self.unbounded).map(|primitive| primitive.acceleration_memory()).sum::<usize>()
+    }
+}
@@ -0,0 +1,257 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB, Image};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::rendering::statistics;
+use crate::textures::Perlin;
+
+use std::mem;
+use std::sync::Arc;
+
+const EPSILON: f64 = 1e-12;
+
+pub struct Heightfield {
+    pub origin: Point3,
+    pub size: Vec3,
+    pub material: Arc<dyn Material>,
+    heights: Vec<f64>,
+    resolution_x: usize,
+    resolution_z: usize,
+    bounds: AABB
+}
+
+impl Heightfield {
+    pub fn new(heights: Vec<f64>, resolution_x: usize, resolution_z: usize, origin: Point3, size: Vec3, material: Arc<dyn Material>) -> Self {
+        assert!(resolution_x >= 2 && resolution_z >= 2, "Heightfield needs at least 2x2 samples.");
+        assert!(heights.len() == resolution_x * resolution_z, "Heightfield sample count does not match its resolution.");
+
+        let heights: Vec<f64> = heights.iter().map(|height| origin.y + height * size.y).collect();
+        let lowest = heights.iter().cloned().fold(f64::INFINITY, f64::min);
+        let highest = heights.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        let padding = 0.0001;
+        let bounds = AABB::new(
+            Point3::new(origin.x - padding, lowest - padding, origin.z - padding),
+            Point3::new(origin.x + size.x + padding, highest + padding, origin.z + size.z + padding)
+        );
+
+        Self {
+            origin: origin,
+            size: size,
+            material: material,
+            heights: heights,
+            resolution_x: resolution_x,
+            resolution_z: resolution_z,
+            bounds: bounds
+        }
+    }
+
+    pub fn from_image(image: &Image, origin: Point3, size: Vec3, material: Arc<dyn Material>) -> Self {
+        let heights = image.buffer.iter().map(|color| (color.x + color.y + color.z) / 3.0).collect();
+        Self::new(heights, image.width, image.height, origin, size, material)
+    }
+
+    pub fn from_noise(perlin: &Perlin, resolution_x: usize, resolution_z: usize, frequency: f64, origin: Point3, size: Vec3, material: Arc<dyn Material>) -> Self {
+        let mut heights = Vec::with_capacity(resolution_x * resolution_z);
+
+        for z in 0..resolution_z {
+            for x in 0..resolution_x {
+                heights.push(perlin.turbulence(Point3::new(x as f64 * frequency, 0.0, z as f64 * frequency)));
+            }
+        }
+
+        let highest = heights.iter().cloned().fold(0.0, f64::max);
+        if highest > 0.0 {
+            heights.iter_mut().for_each(|height| *height /= highest);
+        }
+
+        Self::new(heights, resolution_x, resolution_z, origin, size, material)
+    }
+
+    pub fn resolution(&self) -> (usize, usize) {
+        (self.resolution_x, self.resolution_z)
+    }
+
+    pub fn height_at(&self, x: f64, z: f64) -> Option<f64> {
+        let gx = (x - self.origin.x) / self.cell_size_x();
+        let gz = (z - self.origin.z) / self.cell_size_z();
+
+        if gx < 0.0 || gz < 0.0 || gx > (self.resolution_x - 1) as f64 || gz > (self.resolution_z - 1) as f64 {
+            return None
+        }
+
+        let ix = usize::min(gx as usize, self.resolution_x - 2);
+        let iz = usize::min(gz as usize, self.resolution_z - 2);
+        let (fx, fz) = (gx - ix as f64, gz - iz as f64);
+
+        let near = self.height(ix, iz) * (1.0 - fx) + self.height(ix + 1, iz) * fx;
+        let far = self.height(ix, iz + 1) * (1.0 - fx) + self.height(ix + 1, iz + 1) * fx;
+
+        Some(near * (1.0 - fz) + far * fz)
+    }
+
+    fn cell_size_x(&self) -> f64 {
+        self.size.x / (self.resolution_x - 1) as f64
+    }
+
+    fn cell_size_z(&self) -> f64 {
+        self.size.z / (self.resolution_z - 1) as f64
+    }
+
+    fn height(&self, x: usize, z: usize) -> f64 {
+        self.heights[z * self.resolution_x + x]
+    }
+
+    fn vertex(&self, x: usize, z: usize) -> Point3 {
+        Point3::new(self.origin.x + x as f64 * self.cell_size_x(), self.height(x, z), self.origin.z + z as f64 * self.cell_size_z())
+    }
+
+    fn vertex_normal(&self, x: usize, z: usize) -> Vec3 {
+        let (left, right) = (x.saturating_sub(1), usize::min(x + 1, self.resolution_x - 1));
+        let (back, front) = (z.saturating_sub(1), usize::min(z + 1, self.resolution_z - 1));
+
+        let slope_x = (self.height(right, z) - self.height(left, z)) / ((right - left) as f64 * self.cell_size_x());
+        let slope_z = (self.height(x, front) - self.height(x, back)) / ((front - back) as f64 * self.cell_size_z());
+
+        Vec3::new(-slope_x, 1.0, -slope_z).normalized()
+    }
+
+    fn intersect_triangle(ray: &Ray, vertices: [Point3; 3], t_min: f64, t_max: f64) -> Option<(f64, f64, f64)> {
+        statistics::record_primitive_test();
+
+        let edge_1 = vertices[1] - vertices[0];
+        let edge_2 = vertices[2] - vertices[0];
+        let p = Vec3::cross(&ray.direction, &edge_2);
+        let determinant = Vec3::dot(&edge_1, &p);
+
+        if determinant.abs() < EPSILON {
+            return None
+        }
+
+        let inverse_determinant = 1.0 / determinant;
+        let s = ray.origin - vertices[0];
+        let u = Vec3::dot(&s, &p) * inverse_determinant;
+
+        if !(0.0..=1.0).contains(&u) {
+            return None
+        }
+
+        let q = Vec3::cross(&s, &edge_1);
+        let v = Vec3::dot(&ray.direction, &q) * inverse_determinant;
+
+        if v < 0.0 || u + v > 1.0 {
+            return None
+        }
+
+        let t = Vec3::dot(&edge_2, &q) * inverse_determinant;
+
+        match t_min < t && t < t_max {
+            true => Some((t, u, v)),
+            false => None
+        }
+    }
+
+    fn intersect_cell(&self, ray: &Ray, x: usize, z: usize, t_min: f64, t_max: f64) -> Option<(f64, Vec3, f64, f64)> {
+        let corners = [(x, z), (x + 1, z), (x + 1, z + 1), (x, z + 1)];
+        let mut closest = None;
+        let mut t_max = t_max;
+
+        for triangle in &[[0, 1, 2], [0, 2, 3]] {
+            let indices = [corners[triangle[0]], corners[triangle[1]], corners[triangle[2]]];
+            let vertices = indices.map(|(x, z)| self.vertex(x, z));
+
+            if let Some((t, u, v)) = Self::intersect_triangle(ray, vertices, t_min, t_max) {
+                let normals = indices.map(|(x, z)| self.vertex_normal(x, z));
+                let normal = (1.0 - u - v) * normals[0] + u * normals[1] + v * normals[2];
+
+                let point = ray.at(t);
+                let tex_u = (point.x - self.origin.x) / self.size.x;
+                let tex_v = (point.z - self.origin.z) / self.size.z;
+
+                t_max = t;
+                closest = Some((t, normal, tex_u, tex_v));
+            }
+        }
+
+        closest
+    }
+
+    fn traverse(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, Vec3, f64, f64)> {
+        let (start, end) = self.bounds.hit_interval(ray, t_min, t_max)?;
+
+        let cells_x = self.resolution_x - 1;
+        let cells_z = self.resolution_z - 1;
+        let (cell_x, cell_z) = (self.cell_size_x(), self.cell_size_z());
+
+        let entry = ray.at(start);
+        let mut x = f64::clamp(((entry.x - self.origin.x) / cell_x).floor(), 0.0, (cells_x - 1) as f64) as usize;
+        let mut z = f64::clamp(((entry.z - self.origin.z) / cell_z).floor(), 0.0, (cells_z - 1) as f64) as usize;
+
+        let axis = |direction: f64, position: f64, cell: usize, size: f64, origin: f64| -> (f64, f64) {
+            match direction {
+                d if d > 0.0 => (start + (origin + (cell + 1) as f64 * size - position) / d, size / d),
+                d if d < 0.0 => (start + (origin + cell as f64 * size - position) / d, -size / d),
+                _ => (f64::INFINITY, f64::INFINITY)
+            }
+        };
+
+        let (mut next_x, delta_x) = axis(ray.direction.x, entry.x, x, cell_x, self.origin.x);
+        let (mut next_z, delta_z) = axis(ray.direction.z, entry.z, z, cell_z, self.origin.z);
+        let mut cell_start = start;
+
+        loop {
+            let cell_end = f64::min(f64::min(next_x, next_z), end);
+
+            let (y_0, y_1) = (ray.at(cell_start).y, ray.at(cell_end).y);
+            let corners = [self.height(x, z), self.height(x + 1, z), self.height(x, z + 1), self.height(x + 1, z + 1)];
+            let lowest = corners.iter().cloned().fold(f64::INFINITY, f64::min);
+            let highest = corners.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+            if f64::min(y_0, y_1) <= highest && f64::max(y_0, y_1) >= lowest {
+                if let Some(hit) = self.intersect_cell(ray, x, z, t_min, t_max) {
+                    return Some(hit)
+                }
+            }
+
+            if cell_end >= end {
+                return None
+            }
+
+            if next_x < next_z {
+                match ray.direction.x > 0.0 {
+                    true if x + 1 < cells_x => x += 1,
+                    false if x > 0 => x -= 1,
+                    _ => return None
+                }
+                cell_start = next_x;
+                next_x += delta_x;
+            } else {
+                match ray.direction.z > 0.0 {
+                    true if z + 1 < cells_z => z += 1,
+                    false if z > 0 => z -= 1,
+                    _ => return None
+                }
+                cell_start = next_z;
+                next_z += delta_z;
+            }
+        }
+    }
+}
+
+impl Hittable for Heightfield {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, normal, u, v) = self.traverse(ray, t_min, t_max)?;
+        Some(HitRecord::new(ray.at(t), normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.traverse(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        Some(self.bounds)
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        mem::size_of::<Self>() + self.heights.capacity() * mem::size_of::<f64>()
+    }
+}
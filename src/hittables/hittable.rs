@@ -1,8 +1,34 @@
 use crate::structures::{Ray, HitRecord, AABB};
 
+const HIT_ALL_EPSILON: f64 = 1e-7;
+
 pub trait Hittable: Send + Sync {
     
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord>;
 
     fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB>;
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.hit(ray, t_min, t_max).is_some()
+    }
+
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        let mut hits = Vec::new();
+        let mut t_min = t_min;
+
+        while let Some(hit) = self.hit(ray, t_min, t_max) {
+            if !hit.t.is_finite() {
+                break
+            }
+
+            t_min = hit.t + HIT_ALL_EPSILON * f64::max(1.0, hit.t.abs());
+            hits.push(hit);
+        }
+
+        hits
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        0
+    }
 }
\ No newline at end of file
@@ -41,6 +41,10 @@ impl Hittable for HittableList {
         current_hit
     }
 
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.hittables.iter().any(|hittable| hittable.occluded(ray, t_min, t_max))
+    }
+
     fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
         if self.hittables.is_empty() {
             return None
@@ -65,4 +69,8 @@ impl Hittable for HittableList {
 
         Some(total)
     }
+
+    fn acceleration_memory(&self) -> usize {
+        self.hittables.iter().map(|hittable| hittable.acceleration_memory()).sum()
+    }
 }
\ No newline at end of file
@@ -20,17 +20,24 @@ impl Instance {
 impl Hittable for Instance {
     
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
-        let ray = self.transform.inverse_transform_ray(*ray);
-        match self.hittable.hit(&ray, t_min, t_max) {
+        let (ray, scale) = self.transform.inverse_transform_ray_with_scale(*ray);
+        match self.hittable.hit(&ray, t_min * scale, t_max * scale) {
             Some(mut record) => {
                 record.point = self.transform.transform_point(record.point);
-                record.normal = self.transform.transform_vector(record.normal);
+                record.normal = self.transform.transform_normal(record.normal);
+                record.tangent = record.tangent.map(|tangent| self.transform.transform_vector(tangent).normalized());
+                record.t /= scale;
                 Some(record)
             },
             None => None
         }
     }
 
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        let (ray, scale) = self.transform.inverse_transform_ray_with_scale(*ray);
+        self.hittable.occluded(&ray, t_min * scale, t_max * scale)
+    }
+
     fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
         match self.hittable.bounding_box(time_0, time_1) {
             Some(aabb) => {
@@ -40,4 +47,8 @@ impl Hittable for Instance {
             None => None
         }
     }
+
+    fn acceleration_memory(&self) -> usize {
+        self.hittable.acceleration_memory()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,133 @@
+use crate::structures::{AABB, HitRecord, Ray, Transform};
+use crate::hittables::{Hittable, Instance, BVHBuilder};
+use crate::hittables::linear_bvh::LinearBVH;
+
+use std::sync::Arc;
+use std::mem;
+
+pub struct InstanceBVH {
+    pub builder: BVHBuilder,
+    bvh: Option<LinearBVH>,
+    instances: Vec<Instance>,
+    positions: Vec<usize>,
+    leaves: Vec<u32>,
+    dirty: Vec<u32>
+}
+
+impl InstanceBVH {
+    pub fn new(instances: Vec<Instance>, time_0: f64, time_1: f64) -> Self {
+        Self::with_builder(instances, &BVHBuilder::new(time_0, time_1))
+    }
+
+    pub fn with_builder(instances: Vec<Instance>, builder: &BVHBuilder) -> Self {
+        let builder = builder.with_max_leaf_size(usize::min(builder.max_leaf_size, u16::MAX as usize));
+
+        if instances.is_empty() {
+            return Self {
+                builder: builder,
+                bvh: None,
+                instances: instances,
+                positions: vec![],
+                leaves: vec![],
+                dirty: vec![]
+            }
+        }
+
+        let bounds: Vec<AABB> = instances.iter().map(|instance| Self::instance_bounds(instance, &builder)).collect();
+        let output = builder.build_from_bounds(&bounds);
+
+        let mut positions = vec![0; instances.len()];
+        for (position, &index) in output.order.iter().enumerate() {
+            positions[index] = position;
+        }
+
+        let mut instances: Vec<Option<Instance>> = instances.into_iter().map(Some).collect();
+        let instances: Vec<Instance> = output.order.iter().map(|&index| instances[index].take().unwrap()).collect();
+
+        let bvh = LinearBVH::new(output.root, output.node_count);
+        let leaves = bvh.leaves(instances.len());
+
+        Self {
+            builder: builder,
+            bvh: Some(bvh),
+            instances: instances,
+            positions: positions,
+            leaves: leaves,
+            dirty: vec![]
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    pub fn instance(&self, index: usize) -> &Instance {
+        &self.instances[self.positions[index]]
+    }
+
+    pub fn set_transform(&mut self, index: usize, transform: Transform) {
+        let position = self.positions[index];
+
+        self.instances[position].transform = transform;
+        self.dirty.push(self.leaves[position]);
+    }
+
+    pub fn needs_refit(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    pub fn refit(&mut self) {
+        let instances = &self.instances;
+        let builder = &self.builder;
+
+        if let Some(bvh) = &mut self.bvh {
+            bvh.refit_leaves(&self.dirty, |position| Self::instance_bounds(&instances[position], builder));
+        }
+
+        self.dirty.clear();
+    }
+
+    pub fn rebuild(self) -> Self {
+        let builder = self.builder;
+        let mut instances: Vec<Option<Instance>> = self.instances.into_iter().map(Some).collect();
+        let instances = self.positions.iter().map(|&position| instances[position].take().unwrap()).collect();
+
+        Self::with_builder(instances, &builder)
+    }
+
+    fn instance_bounds(instance: &Instance, builder: &BVHBuilder) -> AABB {
+        match instance.bounding_box(builder.time_0, builder.time_1) {
+            Some(aabb) => aabb,
+            None => panic!("Instance with no bounding box passed into InstanceBVH.")
+        }
+    }
+}
+
+impl Hittable for InstanceBVH {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.bvh.as_ref().and_then(|bvh| bvh.hit(ray, t_min, t_max, |position, t_min, t_max| self.instances[position].hit(ray, t_min, t_max)))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.bvh.as_ref().is_some_and(|bvh| bvh.occluded(ray, t_min, t_max, |position| self.instances[position].occluded(ray, t_min, t_max)))
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        self.bvh.as_ref().map(|bvh| bvh.aabb())
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        let mut geometry: Vec<&Arc<dyn Hittable>> = self.instances.iter().map(|instance| &instance.hittable).collect();
+        geometry.sort_unstable_by_key(|hittable| Arc::as_ptr(hittable) as *const ());
+        geometry.dedup_by(|a, b| Arc::ptr_eq(a, b));
+
+        let own = mem::size_of::<Self>() + self.bvh.as_ref().map_or(0, |bvh| bvh.memory()) + self.instances.capacity() * mem::size_of::<Instance>();
+        let indices = self.positions.capacity() * mem::size_of::<usize>() + self.leaves.capacity() * mem::size_of::<u32>();
+
+        own + indices + geometry.iter().map(|hittable| hittable.acceleration_memory()).sum::<usize>()
+    }
+}
@@ -0,0 +1,199 @@
+use crate::structures::{AABB, HitRecord, Ray, Vec3};
+use crate::hittables::bvh_builder::{BuildNode, MAX_DEPTH};
+use crate::rendering::statistics;
+
+use std::mem;
+
+const STACK_SIZE: usize = MAX_DEPTH + 1;
+const NO_PARENT: u32 = u32::MAX;
+
+#[derive(Clone, Copy)]
+struct LinearNode {
+    aabb: AABB,
+    offset: u32,
+    count: u16,
+    axis: u8
+}
+
+pub(crate) struct LinearBVH {
+    nodes: Vec<LinearNode>,
+    parents: Vec<u32>
+}
+
+impl LinearBVH {
+    pub(crate) fn new(root: BuildNode, node_count: usize) -> Self {
+        let mut bvh = Self {
+            nodes: Vec::with_capacity(node_count),
+            parents: Vec::with_capacity(node_count)
+        };
+
+        bvh.flatten(root, NO_PARENT);
+        bvh
+    }
+
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn aabb(&self) -> AABB {
+        self.nodes[0].aabb
+    }
+
+    pub(crate) fn memory(&self) -> usize {
+        self.nodes.capacity() * mem::size_of::<LinearNode>() + self.parents.capacity() * mem::size_of::<u32>()
+    }
+
+    pub(crate) fn leaves(&self, primitive_count: usize) -> Vec<u32> {
+        let mut leaves = vec![0; primitive_count];
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            if node.count > 0 {
+                let first = node.offset as usize;
+                for leaf in &mut leaves[first..(first + node.count as usize)] {
+                    *leaf = index as u32;
+                }
+            }
+        }
+
+        leaves
+    }
+
+    pub(crate) fn hit<F: FnMut(usize, f64, f64) -> Option<HitRecord>>(&self, ray: &Ray, t_min: f64, t_max: f64, mut hit_primitive: F) -> Option<HitRecord> {
+        let inverse_direction = Vec3::new(1.0 / ray.direction.x, 1.0 / ray.direction.y, 1.0 / ray.direction.z);
+        let direction_is_negative = [inverse_direction.x < 0.0, inverse_direction.y < 0.0, inverse_direction.z < 0.0];
+
+        let mut closest_hit: Option<HitRecord> = None;
+        let mut closest_so_far = t_max;
+
+        let mut stack = [0; STACK_SIZE];
+        let mut stack_size = 0;
+        let mut current = 0;
+
+        loop {
+            let node = &self.nodes[current];
+            statistics::record_bvh_node_test();
+
+            if node.aabb.hit_with_inverse(ray.origin, inverse_direction, t_min, closest_so_far) {
+                if node.count > 0 {
+                    let first = node.offset as usize;
+                    for primitive in first..(first + node.count as usize) {
+                        if let Some(record) = hit_primitive(primitive, t_min, closest_so_far) {
+                            closest_so_far = record.t;
+                            closest_hit = Some(record);
+                        }
+                    }
+                } else if direction_is_negative[node.axis as usize] {
+                    stack[stack_size] = current + 1;
+                    stack_size += 1;
+                    current = node.offset as usize;
+                    continue
+                } else {
+                    stack[stack_size] = node.offset as usize;
+                    stack_size += 1;
+                    current += 1;
+                    continue
+                }
+            }
+
+            if stack_size == 0 {
+                break
+            }
+
+            stack_size -= 1;
+            current = stack[stack_size];
+        }
+
+        closest_hit
+    }
+
+    pub(crate) fn occluded<F: FnMut(usize) -> bool>(&self, ray: &Ray, t_min: f64, t_max: f64, mut occluded_primitive: F) -> bool {
+        let inverse_direction = Vec3::new(1.0 / ray.direction.x, 1.0 / ray.direction.y, 1.0 / ray.direction.z);
+
+        let mut stack = [0; STACK_SIZE];
+        let mut stack_size = 0;
+        let mut current = 0;
+
+        loop {
+            let node = &self.nodes[current];
+            statistics::record_bvh_node_test();
+
+            if node.aabb.hit_with_inverse(ray.origin, inverse_direction, t_min, t_max) {
+                if node.count > 0 {
+                    let first = node.offset as usize;
+                    if (first..(first + node.count as usize)).any(&mut occluded_primitive) {
+                        return true
+                    }
+                } else {
+                    stack[stack_size] = node.offset as usize;
+                    stack_size += 1;
+                    current += 1;
+                    continue
+                }
+            }
+
+            if stack_size == 0 {
+                return false
+            }
+
+            stack_size -= 1;
+            current = stack[stack_size];
+        }
+    }
+
+    pub(crate) fn refit_leaves<F: Fn(usize) -> AABB>(&mut self, leaves: &[u32], bounds: F) {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut changed = vec![];
+
+        for &leaf in leaves {
+            let mut index = leaf;
+            while index != NO_PARENT && !visited[index as usize] {
+                visited[index as usize] = true;
+                changed.push(index as usize);
+                index = self.parents[index as usize];
+            }
+        }
+
+        changed.sort_unstable_by(|a, b| b.cmp(a));
+
+        for index in changed {
+            self.refit_node(index, &bounds);
+        }
+    }
+
+    fn refit_node<F: Fn(usize) -> AABB>(&mut self, index: usize, bounds: &F) {
+        let node = self.nodes[index];
+
+        self.nodes[index].aabb = match node.count > 0 {
+            true => {
+                let first = node.offset as usize;
+                let mut aabb = bounds(first);
+                for primitive in (first + 1)..(first + node.count as usize) {
+                    aabb.encapsulate(bounds(primitive));
+                }
+
+                aabb
+            },
+            false => self.nodes[index + 1].aabb + self.nodes[node.offset as usize].aabb
+        };
+    }
+
+    fn flatten(&mut self, node: BuildNode, parent: u32) -> usize {
+        let index = self.nodes.len();
+        self.parents.push(parent);
+
+        match node {
+            BuildNode::Leaf { aabb, first, count } => {
+                assert!(count <= u16::MAX as usize, "Too many primitives in a single LinearBVH leaf.");
+                self.nodes.push(LinearNode { aabb: aabb, offset: first as u32, count: count as u16, axis: 0 });
+            },
+            BuildNode::Interior { aabb, axis, left, right } => {
+                self.nodes.push(LinearNode { aabb: aabb, offset: 0, count: 0, axis: axis as u8 });
+                self.flatten(*left, index as u32);
+                let second_child = self.flatten(*right, index as u32);
+                self.nodes[index].offset = second_child as u32;
+            }
+        }
+
+        index
+    }
+}
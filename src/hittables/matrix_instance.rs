@@ -0,0 +1,73 @@
+use crate::structures::{Ray, HitRecord, Matrix4, AABB};
+use crate::hittables::Hittable;
+
+use std::sync::Arc;
+
+pub struct MatrixInstance {
+    pub hittable: Arc<dyn Hittable>,
+    matrix: Matrix4,
+    inverse: Matrix4,
+    normal_matrix: Matrix4
+}
+
+impl MatrixInstance {
+    pub fn new(hittable: Arc<dyn Hittable>, matrix: Matrix4) -> Self {
+        let inverse = match matrix.inverse() {
+            Some(inverse) => inverse,
+            None => panic!("Singular matrix passed into MatrixInstance.")
+        };
+
+        Self {
+            hittable: hittable,
+            matrix: matrix,
+            inverse: inverse,
+            normal_matrix: inverse.transpose()
+        }
+    }
+
+    pub fn matrix(&self) -> Matrix4 {
+        self.matrix
+    }
+
+    fn object_ray(&self, ray: &Ray) -> (Ray, f64) {
+        let direction = self.inverse.transform_vector(ray.direction);
+        let scale = direction.length() / ray.direction.length();
+
+        (Ray::with_time(self.inverse.transform_point(ray.origin), direction, ray.time).with_kind(ray.kind), scale)
+    }
+}
+
+impl Hittable for MatrixInstance {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (ray, scale) = self.object_ray(ray);
+        match self.hittable.hit(&ray, t_min * scale, t_max * scale) {
+            Some(mut record) => {
+                record.point = self.matrix.transform_point(record.point);
+                record.normal = self.normal_matrix.transform_vector(record.normal).normalized();
+                record.tangent = record.tangent.map(|tangent| self.matrix.transform_vector(tangent).normalized());
+                record.t /= scale;
+                Some(record)
+            },
+            None => None
+        }
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        let (ray, scale) = self.object_ray(ray);
+        self.hittable.occluded(&ray, t_min * scale, t_max * scale)
+    }
+
+    fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
+        match self.hittable.bounding_box(time_0, time_1) {
+            Some(aabb) => {
+                let points = aabb.get_points().iter().map(|&el| self.matrix.transform_point(el)).collect();
+                Some(AABB::from_points(&points))
+            },
+            None => None
+        }
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        self.hittable.acceleration_memory()
+    }
+}
@@ -4,26 +4,82 @@ pub use self::hittable::Hittable;
 pub mod sphere;
 pub use self::sphere::Sphere;
 
+pub mod disk;
+pub use self::disk::Disk;
+
+pub mod cylinder;
+pub use self::cylinder::Cylinder;
+
+pub mod cone;
+pub use self::cone::Cone;
+
+pub mod torus;
+pub use self::torus::Torus;
+
+pub mod capsule;
+pub use self::capsule::Capsule;
+
+pub mod paraboloid;
+pub use self::paraboloid::Paraboloid;
+
 pub mod hittable_list;
 pub use self::hittable_list::HittableList;
 
 pub mod instance;
 pub use self::instance::Instance;
 
+pub mod matrix_instance;
+pub use self::matrix_instance::MatrixInstance;
+
 pub mod moving_instance;
 pub use self::moving_instance::MovingInstance;
 
+pub mod animated_instance;
+pub use self::animated_instance::{AnimatedInstance, Keyframe, Interpolation};
+
+pub mod object_properties;
+pub use self::object_properties::ObjectProperties;
+
 pub mod bvh_node;
 pub use self::bvh_node::BVHNode;
 
-pub mod xy_rect;
-pub use self::xy_rect::XYRect;
+pub mod bvh_builder;
+pub use self::bvh_builder::{BVHBuilder, BVHBuild};
+
+mod linear_bvh;
+
+pub mod flat_bvh;
+pub use self::flat_bvh::FlatBVH;
+
+pub mod instance_bvh;
+pub use self::instance_bvh::InstanceBVH;
+
+pub mod quad;
+pub use self::quad::Quad;
+
+pub mod triangle;
+pub use self::triangle::Triangle;
+
+pub mod plane;
+pub use self::plane::Plane;
+
+pub mod curve;
+pub use self::curve::{Curve, CurveType};
+
+pub mod heightfield;
+pub use self::heightfield::Heightfield;
+
+pub mod distance_field;
+pub use self::distance_field::DistanceField;
+
+pub mod csg;
+pub use self::csg::{CSG, CSGOperation};
 
-pub mod xz_rect;
-pub use self::xz_rect::XZRect;
+pub mod triangle_mesh;
+pub use self::triangle_mesh::TriangleMesh;
 
-pub mod yz_rect;
-pub use self::yz_rect::YZRect;
+pub mod subdivision_surface;
+pub use self::subdivision_surface::SubdivisionSurface;
 
 pub mod aa_box;
 pub use self::aa_box::AABox;
@@ -0,0 +1,71 @@
+use crate::structures::{Ray, RayVisibility, HitRecord, AABB};
+use crate::hittables::Hittable;
+
+use std::sync::Arc;
+
+pub struct ObjectProperties {
+    pub hittable: Arc<dyn Hittable>,
+    pub visibility: RayVisibility,
+    pub id: Option<u32>
+}
+
+impl ObjectProperties {
+    pub fn new(hittable: Arc<dyn Hittable>) -> Self {
+        Self {
+            hittable: hittable,
+            visibility: RayVisibility::ALL,
+            id: None
+        }
+    }
+
+    pub fn with_visibility(self, visibility: RayVisibility) -> Self {
+        Self {
+            visibility: visibility,
+            ..self
+        }
+    }
+
+    pub fn with_id(self, id: u32) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    fn tag(&self, record: HitRecord) -> HitRecord {
+        HitRecord {
+            object_id: record.object_id.or(self.id),
+            ..record
+        }
+    }
+}
+
+impl Hittable for ObjectProperties {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        if !self.visibility.intersects(ray.kind) {
+            return None
+        }
+
+        self.hittable.hit(ray, t_min, t_max).map(|record| self.tag(record))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.visibility.intersects(ray.kind) && self.hittable.occluded(ray, t_min, t_max)
+    }
+
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        if !self.visibility.intersects(ray.kind) {
+            return Vec::new()
+        }
+
+        self.hittable.hit_all(ray, t_min, t_max).into_iter().map(|record| self.tag(record)).collect()
+    }
+
+    fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
+        self.hittable.bounding_box(time_0, time_1)
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        self.hittable.acceleration_memory()
+    }
+}
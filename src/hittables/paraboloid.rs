@@ -0,0 +1,138 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+use crate::utility::solve_quadratic;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+#[derive(Clone, Copy)]
+enum Part {
+    Body,
+    Top
+}
+
+pub struct Paraboloid {
+    pub center: Point3,
+    pub radius: f64,
+    pub height: f64,
+    pub capped: bool,
+    pub material: Arc<dyn Material>
+}
+
+impl Paraboloid {
+    pub fn new(center: Point3, radius: f64, height: f64, capped: bool, material: Arc<dyn Material>) -> Self {
+        Self {
+            center: center,
+            radius: radius,
+            height: height,
+            capped: capped,
+            material: material
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        let cap = match self.capped {
+            true => PI * self.radius.powi(2),
+            false => 0.0
+        };
+
+        PI * self.radius / (6.0 * self.height.powi(2)) * ((self.radius.powi(2) + 4.0 * self.height.powi(2)).powf(1.5) - self.radius.powi(3)) + cap
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        let s = self.height / self.radius.powi(2);
+        let phi = rng.gen_range(0.0..(2.0 * PI));
+        let cap = match self.capped {
+            true => PI * self.radius.powi(2),
+            false => 0.0
+        };
+
+        if rng.gen_range(0.0..self.area()) < cap {
+            let r = self.radius * rng.gen::<f64>().sqrt();
+            return (self.center + Vec3::new(r * phi.cos(), self.height, r * phi.sin()), Vec3::up())
+        }
+
+        let max_slope = f64::sqrt(1.0 + (2.0 * s * self.radius).powi(2));
+        let r = loop {
+            let r = self.radius * rng.gen::<f64>().sqrt();
+            if rng.gen::<f64>() * max_slope <= f64::sqrt(1.0 + (2.0 * s * r).powi(2)) {
+                break r
+            }
+        };
+
+        let (x, z) = (r * phi.cos(), r * phi.sin());
+        (self.center + Vec3::new(x, s * r * r, z), Vec3::new(2.0 * s * x, -1.0, 2.0 * s * z).normalized())
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, Part)> {
+        statistics::record_primitive_test();
+
+        let p = ray.origin - self.center;
+        let d = ray.direction;
+        let s = self.height / self.radius.powi(2);
+
+        let mut closest: Option<(f64, Part)> = None;
+        let mut t_max = t_max;
+
+        let a = s * (d.x * d.x + d.z * d.z);
+        let b = 2.0 * s * (p.x * d.x + p.z * d.z) - d.y;
+        let c = s * (p.x * p.x + p.z * p.z) - p.y;
+
+        for t in solve_quadratic(a, b, c) {
+            let y = p.y + t * d.y;
+            if t_min < t && t < t_max && 0.0 <= y && y <= self.height {
+                closest = Some((t, Part::Body));
+                t_max = t;
+                break
+            }
+        }
+
+        if self.capped && d.y.abs() > 1e-12 {
+            let t = (self.height - p.y) / d.y;
+            let x = p.x + t * d.x;
+            let z = p.z + t * d.z;
+
+            if t_min < t && t < t_max && x * x + z * z <= self.radius.powi(2) {
+                closest = Some((t, Part::Top));
+            }
+        }
+
+        closest
+    }
+}
+
+impl Hittable for Paraboloid {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, part) = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let p = point - self.center;
+
+        let u = (f64::atan2(-p.z, p.x) + PI) / (2.0 * PI);
+        let (normal, v) = match part {
+            Part::Body => {
+                let s = self.height / self.radius.powi(2);
+                (Vec3::new(2.0 * s * p.x, -1.0, 2.0 * s * p.z), p.y / self.height)
+            },
+            Part::Top => (Vec3::up(), 1.0)
+        };
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let min = self.center - Vec3::new(self.radius, 0.0, self.radius);
+        let max = self.center + Vec3::new(self.radius, self.height, self.radius);
+        Some(AABB::new(min, max))
+    }
+}
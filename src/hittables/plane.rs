@@ -0,0 +1,71 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::rendering::statistics;
+
+use std::sync::Arc;
+
+pub struct Plane {
+    pub point: Point3,
+    pub normal: Vec3,
+    pub material: Arc<dyn Material>,
+    tangent: Vec3,
+    bitangent: Vec3
+}
+
+impl Plane {
+    pub fn new(point: Point3, normal: Vec3, material: Arc<dyn Material>) -> Self {
+        let normal = normal.normalized();
+        let helper = match normal.x.abs() > 0.9 {
+            true => Vec3::up(),
+            false => Vec3::right()
+        };
+        let tangent = Vec3::cross(&helper, &normal).normalized();
+
+        Self {
+            point: point,
+            normal: normal,
+            material: material,
+            tangent: tangent,
+            bitangent: Vec3::cross(&normal, &tangent)
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<f64> {
+        statistics::record_primitive_test();
+
+        let denominator = Vec3::dot(&self.normal, &ray.direction);
+
+        if denominator.abs() < 1e-12 {
+            return None
+        }
+
+        let t = Vec3::dot(&(self.point - ray.origin), &self.normal) / denominator;
+
+        match t_min < t && t < t_max {
+            true => Some(t),
+            false => None
+        }
+    }
+}
+
+impl Hittable for Plane {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let t = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let offset = point - self.point;
+
+        let u = Vec3::dot(&offset, &self.tangent).rem_euclid(1.0);
+        let v = Vec3::dot(&offset, &self.bitangent).rem_euclid(1.0);
+
+        Some(HitRecord::new(point, self.normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        None
+    }
+}
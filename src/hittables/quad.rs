@@ -0,0 +1,115 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+
+use std::sync::Arc;
+
+use rand::Rng;
+
+pub struct Quad {
+    pub origin: Point3,
+    pub u: Vec3,
+    pub v: Vec3,
+    pub material: Arc<dyn Material>,
+    normal: Vec3,
+    distance: f64,
+    w: Vec3
+}
+
+impl Quad {
+    pub fn new(origin: Point3, u: Vec3, v: Vec3, material: Arc<dyn Material>) -> Self {
+        let n = Vec3::cross(&u, &v);
+        assert!(n.squared_length() > 0.0, "Degenerate Quad edges.");
+
+        let normal = n.normalized();
+
+        Self {
+            origin: origin,
+            u: u,
+            v: v,
+            material: material,
+            normal: normal,
+            distance: Vec3::dot(&normal, &origin),
+            w: n / n.squared_length()
+        }
+    }
+
+    pub fn xy_rect(x0: f64, x1: f64, y0: f64, y1: f64, z: f64, material: Arc<dyn Material>) -> Self {
+        Self::new(Point3::new(x0, y0, z), Vec3::new(x1 - x0, 0.0, 0.0), Vec3::new(0.0, y1 - y0, 0.0), material)
+    }
+
+    pub fn xz_rect(x0: f64, x1: f64, z0: f64, z1: f64, y: f64, material: Arc<dyn Material>) -> Self {
+        Self::new(Point3::new(x0, y, z0), Vec3::new(x1 - x0, 0.0, 0.0), Vec3::new(0.0, 0.0, z1 - z0), material).flipped()
+    }
+
+    pub fn yz_rect(y0: f64, y1: f64, z0: f64, z1: f64, x: f64, material: Arc<dyn Material>) -> Self {
+        Self::new(Point3::new(x, y0, z0), Vec3::new(0.0, y1 - y0, 0.0), Vec3::new(0.0, 0.0, z1 - z0), material)
+    }
+
+    pub fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    pub fn area(&self) -> f64 {
+        Vec3::cross(&self.u, &self.v).length()
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+        (self.origin + rng.gen::<f64>() * self.u + rng.gen::<f64>() * self.v, self.normal)
+    }
+
+    fn flipped(self) -> Self {
+        Self {
+            normal: -self.normal,
+            distance: -self.distance,
+            ..self
+        }
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, f64, f64)> {
+        statistics::record_primitive_test();
+
+        let denominator = Vec3::dot(&self.normal, &ray.direction);
+
+        if denominator.abs() < 1e-12 {
+            return None
+        }
+
+        let t = (self.distance - Vec3::dot(&self.normal, &ray.origin)) / denominator;
+
+        if t <= t_min || t >= t_max {
+            return None
+        }
+
+        let planar = ray.at(t) - self.origin;
+        let alpha = Vec3::dot(&self.w, &Vec3::cross(&planar, &self.v));
+        let beta = Vec3::dot(&self.w, &Vec3::cross(&self.u, &planar));
+
+        match (0.0..=1.0).contains(&alpha) && (0.0..=1.0).contains(&beta) {
+            true => Some((t, alpha, beta)),
+            false => None
+        }
+    }
+}
+
+impl Hittable for Quad {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, u, v) = self.intersect(ray, t_min, t_max)?;
+        Some(HitRecord::new(ray.at(t), self.normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let padding = Vec3::new(0.0001, 0.0001, 0.0001);
+        let corners = vec![self.origin, self.origin + self.u, self.origin + self.v, self.origin + self.u + self.v];
+        let aabb = AABB::from_points(&corners);
+
+        Some(AABB::new(aabb.min - padding, aabb.max + padding))
+    }
+}
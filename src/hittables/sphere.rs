@@ -1,6 +1,7 @@
 use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
 use crate::hittables::Hittable;
 use crate::materials::Material;
+use crate::rendering::statistics;
 
 use std::sync::Arc;
 use std::f64::consts::PI;
@@ -33,6 +34,8 @@ impl Sphere {
 
 impl Hittable for Sphere {
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        statistics::record_primitive_test();
+
         let oc = ray.origin - self.center;
         
         let half_b = Vec3::dot(&ray.direction, &oc);
@@ -57,6 +60,48 @@ impl Hittable for Sphere {
         
         return None;
     }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        statistics::record_primitive_test();
+
+        let oc = ray.origin - self.center;
+
+        let half_b = Vec3::dot(&ray.direction, &oc);
+        let c = oc.squared_length() - self.radius.powi(2);
+
+        let delta = half_b.powi(2) - c;
+
+        if delta < 0.0 {
+            return false
+        }
+
+        [-1.0, 1.0].iter().any(|signal| {
+            let hit = -half_b + signal * delta.sqrt();
+            t_min < hit && hit < t_max
+        })
+    }
+
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        statistics::record_primitive_test();
+
+        let oc = ray.origin - self.center;
+
+        let half_b = Vec3::dot(&ray.direction, &oc);
+        let c = oc.squared_length() - self.radius.powi(2);
+
+        let delta = half_b.powi(2) - c;
+
+        if delta < 0.0 {
+            return Vec::new()
+        }
+
+        [-1.0, 1.0].iter().map(|signal| -half_b + signal * delta.sqrt()).filter(|&hit| t_min < hit && hit < t_max).map(|hit| {
+            let p = ray.at(hit);
+            let n = (p - self.center) / self.radius;
+            let (u, v) = self.calc_uv(p);
+            HitRecord::new(p, n, self.material.clone(), hit, u, v)
+        }).collect()
+    }
     
     fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
         let vec = self.radius * Vec3::new(1.0, 1.0, 1.0);
@@ -0,0 +1,274 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::{Hittable, TriangleMesh};
+use crate::materials::Material;
+use crate::rendering::Camera;
+use crate::textures::Texture;
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+struct Topology {
+    faces: Vec<Vec<usize>>,
+    edges: Vec<(usize, usize)>,
+    edge_lookup: HashMap<(usize, usize), usize>,
+    edge_faces: Vec<Vec<usize>>,
+    vertex_faces: Vec<Vec<usize>>,
+    vertex_edges: Vec<Vec<usize>>
+}
+
+impl Topology {
+    fn new(faces: &[Vec<usize>], vertex_count: usize) -> Self {
+        let mut edges = Vec::new();
+        let mut edge_lookup = HashMap::new();
+        let mut edge_faces: Vec<Vec<usize>> = Vec::new();
+        let mut vertex_faces = vec![Vec::new(); vertex_count];
+        let mut vertex_edges = vec![Vec::new(); vertex_count];
+
+        for (face_index, face) in faces.iter().enumerate() {
+            for (i, &a) in face.iter().enumerate() {
+                let b = face[(i + 1) % face.len()];
+                let key = (usize::min(a, b), usize::max(a, b));
+
+                let edge = *edge_lookup.entry(key).or_insert_with(|| {
+                    edges.push(key);
+                    edge_faces.push(Vec::new());
+                    vertex_edges[key.0].push(edges.len() - 1);
+                    vertex_edges[key.1].push(edges.len() - 1);
+                    edges.len() - 1
+                });
+
+                edge_faces[edge].push(face_index);
+                vertex_faces[a].push(face_index);
+            }
+        }
+
+        Self {
+            faces: faces.to_vec(),
+            edges: edges,
+            edge_lookup: edge_lookup,
+            edge_faces: edge_faces,
+            vertex_faces: vertex_faces,
+            vertex_edges: vertex_edges
+        }
+    }
+
+    fn edge(&self, a: usize, b: usize) -> usize {
+        self.edge_lookup[&(usize::min(a, b), usize::max(a, b))]
+    }
+
+    fn is_boundary(&self, edge: usize) -> bool {
+        self.edge_faces[edge].len() != 2
+    }
+
+    fn refine(&self, values: &[Vec3]) -> Vec<Vec3> {
+        let face_points: Vec<Vec3> = self.faces.iter()
+            .map(|face| face.iter().fold(Vec3::zero(), |sum, &vertex| sum + values[vertex]) / face.len() as f64)
+            .collect();
+
+        let edge_points = self.edges.iter().enumerate().map(|(edge, &(a, b))| {
+            match self.is_boundary(edge) {
+                true => (values[a] + values[b]) / 2.0,
+                false => (values[a] + values[b] + face_points[self.edge_faces[edge][0]] + face_points[self.edge_faces[edge][1]]) / 4.0
+            }
+        });
+
+        let vertex_points = values.iter().enumerate().map(|(vertex, &point)| {
+            let boundary: Vec<usize> = self.vertex_edges[vertex].iter().cloned().filter(|&edge| self.is_boundary(edge)).collect();
+            let other = |edge: usize| {
+                let (a, b) = self.edges[edge];
+                values[a + b - vertex]
+            };
+
+            match boundary.len() {
+                0 if !self.vertex_edges[vertex].is_empty() => {
+                    let n = self.vertex_edges[vertex].len() as f64;
+                    let faces = self.vertex_faces[vertex].iter().fold(Vec3::zero(), |sum, &face| sum + face_points[face]) / self.vertex_faces[vertex].len() as f64;
+                    let edges = self.vertex_edges[vertex].iter().fold(Vec3::zero(), |sum, &edge| sum + (point + other(edge)) / 2.0) / n;
+
+                    (faces + 2.0 * edges + (n - 3.0) * point) / n
+                },
+                2 if self.vertex_edges[vertex].len() > 2 => 0.75 * point + 0.125 * (other(boundary[0]) + other(boundary[1])),
+                _ => point
+            }
+        });
+
+        vertex_points.chain(edge_points).chain(face_points.iter().cloned()).collect()
+    }
+
+    fn refined_faces(&self) -> Vec<Vec<usize>> {
+        let vertex_count = self.vertex_faces.len();
+        let edge_count = self.edges.len();
+        let mut faces = Vec::new();
+
+        for (face_index, face) in self.faces.iter().enumerate() {
+            for (i, &vertex) in face.iter().enumerate() {
+                let next = face[(i + 1) % face.len()];
+                let previous = face[(i + face.len() - 1) % face.len()];
+
+                faces.push(vec![
+                    vertex,
+                    vertex_count + self.edge(vertex, next),
+                    vertex_count + edge_count + face_index,
+                    vertex_count + self.edge(previous, vertex)
+                ]);
+            }
+        }
+
+        faces
+    }
+}
+
+pub struct SubdivisionSurface {
+    pub material: Arc<dyn Material>,
+    positions: Vec<Point3>,
+    faces: Vec<Vec<usize>>,
+    uvs: Option<Vec<(f64, f64)>>,
+    level: u32,
+    displacement: Option<(Arc<dyn Texture>, f64)>,
+    mesh: OnceLock<TriangleMesh>
+}
+
+impl SubdivisionSurface {
+    pub fn new(positions: Vec<Point3>, faces: Vec<Vec<usize>>, material: Arc<dyn Material>) -> Self {
+        assert!(!faces.is_empty(), "Empty SubdivisionSurface cage.");
+        assert!(faces.iter().all(|face| face.len() >= 3), "SubdivisionSurface faces need at least three vertices.");
+        assert!(faces.iter().flatten().all(|&index| index < positions.len()), "SubdivisionSurface index out of range.");
+
+        Self {
+            material: material,
+            positions: positions,
+            faces: faces,
+            uvs: None,
+            level: 2,
+            displacement: None,
+            mesh: OnceLock::new()
+        }
+    }
+
+    pub fn with_uvs(self, uvs: Vec<(f64, f64)>) -> Self {
+        assert!(uvs.len() == self.positions.len(), "SubdivisionSurface needs one uv per cage vertex.");
+
+        Self {
+            uvs: Some(uvs),
+            mesh: OnceLock::new(),
+            ..self
+        }
+    }
+
+    pub fn with_level(self, level: u32) -> Self {
+        Self {
+            level: level,
+            mesh: OnceLock::new(),
+            ..self
+        }
+    }
+
+    pub fn with_adaptive_level(self, camera: &Camera, image_height: usize, edge_pixels: f64, max_level: u32) -> Self {
+        let aabb = AABB::from_points(&self.positions);
+        let nearest = Point3::new(
+            f64::clamp(camera.position.x, aabb.min.x, aabb.max.x),
+            f64::clamp(camera.position.y, aabb.min.y, aabb.max.y),
+            f64::clamp(camera.position.z, aabb.min.z, aabb.max.z)
+        );
+
+        let longest_edge = self.faces.iter()
+            .flat_map(|face| face.iter().zip(face.iter().cycle().skip(1)).map(|(&a, &b)| (self.positions[a] - self.positions[b]).length()))
+            .fold(0.0, f64::max);
+
+        let target = (nearest - camera.position).length() * camera.pixel_size(image_height) * edge_pixels;
+        let level = match target > 0.0 {
+            true => f64::clamp((longest_edge / target).log2().ceil(), 0.0, max_level as f64) as u32,
+            false => max_level
+        };
+
+        self.with_level(level)
+    }
+
+    pub fn with_displacement(self, texture: Arc<dyn Texture>, scale: f64) -> Self {
+        Self {
+            displacement: Some((texture, scale)),
+            mesh: OnceLock::new(),
+            ..self
+        }
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn mesh(&self) -> &TriangleMesh {
+        self.mesh.get_or_init(|| self.tessellate())
+    }
+
+    pub fn tessellate(&self) -> TriangleMesh {
+        let mut faces = self.faces.clone();
+        let mut positions = self.positions.clone();
+        let mut uvs: Option<Vec<Vec3>> = self.uvs.as_ref().map(|uvs| uvs.iter().map(|&(u, v)| Vec3::new(u, v, 0.0)).collect());
+
+        for _ in 0..self.level {
+            let topology = Topology::new(&faces, positions.len());
+
+            positions = topology.refine(&positions);
+            uvs = uvs.map(|uvs| topology.refine(&uvs));
+            faces = topology.refined_faces();
+        }
+
+        let triangles: Vec<[usize; 3]> = faces.iter()
+            .flat_map(|face| (1..face.len() - 1).map(move |i| [face[0], face[i], face[i + 1]]))
+            .collect();
+
+        let mut normals = Self::vertex_normals(&positions, &triangles);
+
+        if let Some((texture, scale)) = &self.displacement {
+            for (i, position) in positions.iter_mut().enumerate() {
+                let (u, v) = uvs.as_ref().map_or((0.0, 0.0), |uvs| (uvs[i].x, uvs[i].y));
+                let value = texture.value(u, v, *position);
+
+                *position += normals[i] * (scale * (value.x + value.y + value.z) / 3.0);
+            }
+
+            normals = Self::vertex_normals(&positions, &triangles);
+        }
+
+        let mesh = TriangleMesh::new(positions, triangles, self.material.clone()).with_normals(normals);
+
+        match uvs {
+            Some(uvs) => mesh.with_uvs(uvs.iter().map(|uv| (uv.x, uv.y)).collect()),
+            None => mesh
+        }
+    }
+
+    fn vertex_normals(positions: &[Point3], triangles: &[[usize; 3]]) -> Vec<Vec3> {
+        let mut normals = vec![Vec3::zero(); positions.len()];
+
+        for &[a, b, c] in triangles {
+            let normal = Vec3::cross(&(positions[b] - positions[a]), &(positions[c] - positions[a]));
+            normals[a] += normal;
+            normals[b] += normal;
+            normals[c] += normal;
+        }
+
+        normals.iter().map(|normal| match normal.squared_length() > 0.0 {
+            true => normal.normalized(),
+            false => *normal
+        }).collect()
+    }
+}
+
+impl Hittable for SubdivisionSurface {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.mesh().hit(ray, t_min, t_max)
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.mesh().occluded(ray, t_min, t_max)
+    }
+
+    fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
+        self.mesh().bounding_box(time_0, time_1)
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        self.mesh().acceleration_memory()
+    }
+}
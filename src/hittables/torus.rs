@@ -0,0 +1,102 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+use crate::utility::solve_quartic;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+pub struct Torus {
+    pub center: Point3,
+    pub major_radius: f64,
+    pub minor_radius: f64,
+    pub material: Arc<dyn Material>
+}
+
+impl Torus {
+    pub fn new(center: Point3, major_radius: f64, minor_radius: f64, material: Arc<dyn Material>) -> Self {
+        Self {
+            center: center,
+            major_radius: major_radius,
+            minor_radius: minor_radius,
+            material: material
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        4.0 * PI * PI * self.major_radius * self.minor_radius
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        let phi = rng.gen_range(0.0..(2.0 * PI));
+        let theta = loop {
+            let theta = rng.gen_range(0.0..(2.0 * PI));
+            if rng.gen::<f64>() * (self.major_radius + self.minor_radius) <= self.major_radius + self.minor_radius * theta.cos() {
+                break theta
+            }
+        };
+
+        let normal = Vec3::new(theta.cos() * phi.cos(), theta.sin(), theta.cos() * phi.sin());
+        (self.center + self.major_radius * Vec3::new(phi.cos(), 0.0, phi.sin()) + self.minor_radius * normal, normal)
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<f64> {
+        statistics::record_primitive_test();
+
+        let d = ray.direction;
+        let dd = d.squared_length();
+        let bounding_radius = self.major_radius + self.minor_radius;
+
+        let origin = ray.origin - self.center;
+        let start = f64::max(0.0, -Vec3::dot(&origin, &d) / dd - bounding_radius / dd.sqrt());
+        let p = origin + start * d;
+
+        let major = self.major_radius.powi(2);
+        let e = p.squared_length() + major - self.minor_radius.powi(2);
+        let f = Vec3::dot(&p, &d);
+
+        let roots = solve_quartic(
+            dd * dd,
+            4.0 * dd * f,
+            2.0 * dd * e + 4.0 * f * f - 4.0 * major * (d.x * d.x + d.z * d.z),
+            4.0 * f * e - 8.0 * major * (p.x * d.x + p.z * d.z),
+            e * e - 4.0 * major * (p.x * p.x + p.z * p.z)
+        );
+
+        roots.into_iter().map(|t| t + start).find(|&t| t_min < t && t < t_max)
+    }
+}
+
+impl Hittable for Torus {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let t = self.intersect(ray, t_min, t_max)?;
+        let point = ray.at(t);
+        let p = point - self.center;
+
+        let ring = f64::sqrt(p.x * p.x + p.z * p.z);
+        let normal = match ring > 0.0 {
+            true => Vec3::new(p.x * (1.0 - self.major_radius / ring), p.y, p.z * (1.0 - self.major_radius / ring)),
+            false => Vec3::up()
+        };
+
+        let u = (f64::atan2(-p.z, p.x) + PI) / (2.0 * PI);
+        let v = (f64::atan2(p.y, ring - self.major_radius) + PI) / (2.0 * PI);
+
+        Some(HitRecord::new(point, normal, self.material.clone(), t, u, v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let extent = Vec3::new(self.major_radius + self.minor_radius, self.minor_radius, self.major_radius + self.minor_radius);
+        Some(AABB::new(self.center - extent, self.center + extent))
+    }
+}
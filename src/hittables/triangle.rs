@@ -0,0 +1,111 @@
+use crate::structures::{Vec3, Point3, Ray, HitRecord, AABB};
+use crate::hittables::Hittable;
+use crate::materials::Material;
+use crate::random::thread_rng;
+use crate::rendering::statistics;
+
+use std::sync::Arc;
+
+use rand::Rng;
+
+pub struct Triangle {
+    pub a: Point3,
+    pub b: Point3,
+    pub c: Point3,
+    pub uvs: [(f64, f64); 3],
+    pub material: Arc<dyn Material>
+}
+
+impl Triangle {
+    pub fn new(a: Point3, b: Point3, c: Point3, material: Arc<dyn Material>) -> Self {
+        Self {
+            a: a,
+            b: b,
+            c: c,
+            uvs: [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+            material: material
+        }
+    }
+
+    pub fn with_uvs(self, uvs: [(f64, f64); 3]) -> Self {
+        Self {
+            uvs: uvs,
+            ..self
+        }
+    }
+
+    pub fn normal(&self) -> Vec3 {
+        Vec3::cross(&(self.b - self.a), &(self.c - self.a)).normalized()
+    }
+
+    pub fn area(&self) -> f64 {
+        0.5 * Vec3::cross(&(self.b - self.a), &(self.c - self.a)).length()
+    }
+
+    pub fn sample_surface(&self) -> (Point3, Vec3) {
+        let mut rng = thread_rng();
+
+        let r = rng.gen::<f64>().sqrt();
+        let s = rng.gen::<f64>();
+
+        (self.a * (1.0 - r) + self.b * (r * (1.0 - s)) + self.c * (r * s), self.normal())
+    }
+
+    fn intersect(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, f64, f64)> {
+        statistics::record_primitive_test();
+
+        let edge_1 = self.b - self.a;
+        let edge_2 = self.c - self.a;
+        let p = Vec3::cross(&ray.direction, &edge_2);
+        let determinant = Vec3::dot(&edge_1, &p);
+
+        if determinant.abs() < 1e-12 {
+            return None
+        }
+
+        let inverse_determinant = 1.0 / determinant;
+        let s = ray.origin - self.a;
+        let u = Vec3::dot(&s, &p) * inverse_determinant;
+
+        if !(0.0..=1.0).contains(&u) {
+            return None
+        }
+
+        let q = Vec3::cross(&s, &edge_1);
+        let v = Vec3::dot(&ray.direction, &q) * inverse_determinant;
+
+        if v < 0.0 || u + v > 1.0 {
+            return None
+        }
+
+        let t = Vec3::dot(&edge_2, &q) * inverse_determinant;
+
+        match t_min < t && t < t_max {
+            true => Some((t, u, v)),
+            false => None
+        }
+    }
+}
+
+impl Hittable for Triangle {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, u, v) = self.intersect(ray, t_min, t_max)?;
+
+        let w = 1.0 - u - v;
+        let tex_u = w * self.uvs[0].0 + u * self.uvs[1].0 + v * self.uvs[2].0;
+        let tex_v = w * self.uvs[0].1 + u * self.uvs[1].1 + v * self.uvs[2].1;
+
+        Some(HitRecord::new(ray.at(t), self.normal(), self.material.clone(), t, tex_u, tex_v))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.intersect(ray, t_min, t_max).is_some()
+    }
+
+    fn bounding_box(&self, _: f64, _: f64) -> Option<AABB> {
+        let padding = Vec3::new(0.0001, 0.0001, 0.0001);
+        let aabb = AABB::from_points(&vec![self.a, self.b, self.c]);
+
+        Some(AABB::new(aabb.min - padding, aabb.max + padding))
+    }
+}
@@ -0,0 +1,246 @@
+use crate::structures::{AABB, Color, HitRecord, Point3, Ray, Vec3};
+use crate::hittables::{Hittable, BVHBuilder};
+use crate::hittables::linear_bvh::LinearBVH;
+use crate::materials::Material;
+use crate::rendering::statistics;
+use crate::utility::InverseLerp;
+
+use std::mem;
+use std::sync::Arc;
+
+const EPSILON: f64 = 1e-12;
+
+pub struct TriangleMesh {
+    pub material: Arc<dyn Material>,
+    pub time_0: f64,
+    pub time_1: f64,
+    positions: Vec<Vec<Point3>>,
+    indices: Vec<[usize; 3]>,
+    uvs: Option<Vec<(f64, f64)>>,
+    normals: Option<Vec<Vec3>>,
+    colors: Option<Vec<Color>>,
+    bvh: LinearBVH
+}
+
+impl TriangleMesh {
+    pub fn new(positions: Vec<Point3>, indices: Vec<[usize; 3]>, material: Arc<dyn Material>) -> Self {
+        Self::with_motion(vec![positions], indices, material, 0.0, 1.0)
+    }
+
+    pub fn with_motion(position_samples: Vec<Vec<Point3>>, indices: Vec<[usize; 3]>, material: Arc<dyn Material>, time_0: f64, time_1: f64) -> Self {
+        Self::build(position_samples, indices, material, time_0, time_1)
+    }
+
+    pub fn with_uvs(self, uvs: Vec<(f64, f64)>) -> Self {
+        assert!(uvs.len() == self.vertex_count(), "TriangleMesh needs one uv per vertex.");
+
+        Self {
+            uvs: Some(uvs),
+            ..self
+        }
+    }
+
+    pub fn with_normals(self, normals: Vec<Vec3>) -> Self {
+        assert!(normals.len() == self.vertex_count(), "TriangleMesh needs one normal per vertex.");
+
+        Self {
+            normals: Some(normals),
+            ..self
+        }
+    }
+
+    pub fn with_colors(self, colors: Vec<Color>) -> Self {
+        assert!(colors.len() == self.vertex_count(), "TriangleMesh needs one color per vertex.");
+
+        Self {
+            colors: Some(colors),
+            ..self
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions[0].len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn time_sample_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn indices(&self) -> &[[usize; 3]] {
+        &self.indices
+    }
+
+    pub fn uvs(&self) -> Option<&[(f64, f64)]> {
+        self.uvs.as_deref()
+    }
+
+    pub fn normals(&self) -> Option<&[Vec3]> {
+        self.normals.as_deref()
+    }
+
+    pub fn colors(&self) -> Option<&[Color]> {
+        self.colors.as_deref()
+    }
+
+    pub fn positions_at(&self, time: f64) -> Vec<Point3> {
+        (0..self.vertex_count()).map(|vertex| self.position_at(vertex, time)).collect()
+    }
+
+    fn build(position_samples: Vec<Vec<Point3>>, indices: Vec<[usize; 3]>, material: Arc<dyn Material>, time_0: f64, time_1: f64) -> Self {
+        assert!(!position_samples.is_empty(), "TriangleMesh needs at least one position sample.");
+        assert!(!indices.is_empty(), "Empty TriangleMesh.");
+
+        let vertex_count = position_samples[0].len();
+        assert!(position_samples.iter().all(|positions| positions.len() == vertex_count), "TriangleMesh position samples differ in vertex count.");
+        assert!(indices.iter().flatten().all(|&index| index < vertex_count), "TriangleMesh index out of range.");
+
+        let bounds: Vec<AABB> = indices.iter().map(|triangle| {
+            let mut aabb = AABB::new(position_samples[0][triangle[0]], position_samples[0][triangle[0]]);
+            for positions in &position_samples {
+                for &vertex in triangle {
+                    aabb.encapsulate_point(positions[vertex]);
+                }
+            }
+
+            aabb
+        }).collect();
+
+        let builder = BVHBuilder::new(time_0, time_1);
+        let output = builder.build_from_bounds(&bounds);
+        let indices = output.order.iter().map(|&triangle| indices[triangle]).collect();
+
+        Self {
+            material: material,
+            time_0: time_0,
+            time_1: time_1,
+            positions: position_samples,
+            indices: indices,
+            uvs: None,
+            normals: None,
+            colors: None,
+            bvh: LinearBVH::new(output.root, output.node_count)
+        }
+    }
+
+    fn position_at(&self, vertex: usize, time: f64) -> Point3 {
+        let last = self.positions.len() - 1;
+
+        if last == 0 {
+            return self.positions[0][vertex]
+        }
+
+        let sample = f64::clamp(f64::inverse_lerp(self.time_0, self.time_1, time), 0.0, 1.0) * last as f64;
+        let index = usize::min(sample.floor() as usize, last - 1);
+        let t = sample - index as f64;
+
+        Vec3::lerp(&self.positions[index][vertex], &self.positions[index + 1][vertex], t)
+    }
+
+    fn intersect(&self, triangle: usize, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, f64, f64, [Point3; 3])> {
+        statistics::record_primitive_test();
+
+        let [a, b, c] = self.indices[triangle];
+        let vertices = [self.position_at(a, ray.time), self.position_at(b, ray.time), self.position_at(c, ray.time)];
+
+        let edge_1 = vertices[1] - vertices[0];
+        let edge_2 = vertices[2] - vertices[0];
+        let p = Vec3::cross(&ray.direction, &edge_2);
+        let determinant = Vec3::dot(&edge_1, &p);
+
+        if determinant.abs() < EPSILON {
+            return None
+        }
+
+        let inverse_determinant = 1.0 / determinant;
+        let s = ray.origin - vertices[0];
+        let u = Vec3::dot(&s, &p) * inverse_determinant;
+
+        if !(0.0..=1.0).contains(&u) {
+            return None
+        }
+
+        let q = Vec3::cross(&s, &edge_1);
+        let v = Vec3::dot(&ray.direction, &q) * inverse_determinant;
+
+        if v < 0.0 || u + v > 1.0 {
+            return None
+        }
+
+        let t = Vec3::dot(&edge_2, &q) * inverse_determinant;
+
+        match t_min < t && t < t_max {
+            true => Some((t, u, v, vertices)),
+            false => None
+        }
+    }
+
+    fn hit_triangle(&self, triangle: usize, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, u, v, vertices) = self.intersect(triangle, ray, t_min, t_max)?;
+        let [a, b, c] = self.indices[triangle];
+        let w = 1.0 - u - v;
+
+        let geometric_normal = Vec3::cross(&(vertices[1] - vertices[0]), &(vertices[2] - vertices[0]));
+        let normal = match &self.normals {
+            Some(normals) => match w * normals[a] + u * normals[b] + v * normals[c] {
+                normal if normal.squared_length() > EPSILON => normal,
+                _ => geometric_normal
+            },
+            None => geometric_normal
+        };
+
+        let (tex_u, tex_v) = match &self.uvs {
+            Some(uvs) => (w * uvs[a].0 + u * uvs[b].0 + v * uvs[c].0, w * uvs[a].1 + u * uvs[b].1 + v * uvs[c].1),
+            None => (u, v)
+        };
+
+        let record = HitRecord::new(ray.at(t), normal, self.material.clone(), t, tex_u, tex_v);
+
+        match &self.colors {
+            Some(colors) => Some(record.with_color(w * colors[a] + u * colors[b] + v * colors[c])),
+            None => Some(record)
+        }
+    }
+}
+
+impl Hittable for TriangleMesh {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.bvh.hit(ray, t_min, t_max, |triangle, t_min, t_max| self.hit_triangle(triangle, ray, t_min, t_max))
+    }
+
+    fn occluded(&self, ray: &Ray, t_min: f64, t_max: f64) -> bool {
+        self.bvh.occluded(ray, t_min, t_max, |triangle| self.intersect(triangle, ray, t_min, t_max).is_some())
+    }
+
+    fn bounding_box(&self, time_0: f64, time_1: f64) -> Option<AABB> {
+        if self.positions.len() == 1 || (time_0 <= self.time_0 && time_1 >= self.time_1) {
+            return Some(self.bvh.aabb())
+        }
+
+        let last = self.positions.len() - 1;
+        let mut times = vec![time_0, time_1];
+        times.extend((1..last).map(|sample| self.time_0 + (self.time_1 - self.time_0) * sample as f64 / last as f64).filter(|&time| time_0 < time && time < time_1));
+
+        let first = self.position_at(0, time_0);
+        let mut aabb = AABB::new(first, first);
+        for time in times {
+            for vertex in 0..self.vertex_count() {
+                aabb.encapsulate_point(self.position_at(vertex, time));
+            }
+        }
+
+        Some(aabb)
+    }
+
+    fn acceleration_memory(&self) -> usize {
+        let positions = self.positions.iter().map(|positions| positions.capacity() * mem::size_of::<Point3>()).sum::<usize>();
+        let uvs = self.uvs.as_ref().map_or(0, |uvs| uvs.capacity() * mem::size_of::<(f64, f64)>());
+        let normals = self.normals.as_ref().map_or(0, |normals| normals.capacity() * mem::size_of::<Vec3>());
+        let colors = self.colors.as_ref().map_or(0, |colors| colors.capacity() * mem::size_of::<Color>());
+
+        mem::size_of::<Self>() + self.bvh.memory() + positions + uvs + normals + colors + self.indices.capacity() * mem::size_of::<[usize; 3]>()
+    }
+}
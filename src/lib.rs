@@ -1,7 +1,11 @@
 pub mod structures;
 pub mod utility;
+pub mod random;
 pub mod hittables;
+pub mod loaders;
 pub mod materials;
 pub mod rendering;
+pub mod scene;
+pub mod sdf;
 pub mod textures;
 pub mod skyboxes;
\ No newline at end of file
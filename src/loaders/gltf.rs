@@ -0,0 +1,289 @@
+use crate::structures::{Vec3, Point3, Color, Image, Matrix4, Quaternion, Transform};
+use crate::hittables::{Hittable, HittableList, TriangleMesh};
+use crate::materials::{Material, Lambertian, Metal, Dieletric, DiffuseLight};
+use crate::textures::{Texture, SolidColor, ImageTexture, VertexColor};
+use crate::rendering::Camera;
+use crate::rendering::serialization::invalid_data;
+use crate::scene::{SceneGraph, NodeId};
+
+use gltf::{Document, Node, Primitive};
+use gltf::buffer::Data as BufferData;
+use gltf::camera::Projection;
+use gltf::image::{Data as ImageData, Format};
+use gltf::mesh::Mode;
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+
+pub struct GltfScene {
+    pub graph: SceneGraph,
+    pub cameras: Vec<Camera>
+}
+
+struct Importer {
+    document: Document,
+    buffers: Vec<BufferData>,
+    images: Vec<ImageData>,
+    aspect_ratio: f64,
+    meshes: HashMap<usize, Option<Arc<dyn Hittable>>>,
+    materials: HashMap<(Option<usize>, bool), Arc<dyn Material>>,
+    camera_nodes: Vec<(NodeId, usize)>
+}
+
+pub fn read_gltf(path: &str, aspect_ratio: f64) -> io::Result<GltfScene> {
+    let (document, buffers, images) = gltf::import(path).map_err(to_io_error)?;
+    Importer::new(document, buffers, images, aspect_ratio).import()
+}
+
+pub fn parse_gltf(bytes: &[u8], aspect_ratio: f64) -> io::Result<GltfScene> {
+    let (document, buffers, images) = gltf::import_slice(bytes).map_err(to_io_error)?;
+    Importer::new(document, buffers, images, aspect_ratio).import()
+}
+
+fn to_io_error(error: gltf::Error) -> io::Error {
+    match error {
+        gltf::Error::Io(error) => error,
+        error => invalid_data(&format!("Invalid glTF file: {}", error))
+    }
+}
+
+impl Importer {
+    fn new(document: Document, buffers: Vec<BufferData>, images: Vec<ImageData>, aspect_ratio: f64) -> Self {
+        Self {
+            document: document,
+            buffers: buffers,
+            images: images,
+            aspect_ratio: aspect_ratio,
+            meshes: HashMap::new(),
+            materials: HashMap::new(),
+            camera_nodes: Vec::new()
+        }
+    }
+
+    fn import(mut self) -> io::Result<GltfScene> {
+        let document = self.document.clone();
+        let scene = document.default_scene().or_else(|| document.scenes().next())
+            .ok_or_else(|| invalid_data("glTF file contains no scenes."))?;
+
+        let mut graph = SceneGraph::new();
+        let root = graph.root();
+        for node in scene.nodes() {
+            self.import_node(&mut graph, root, &node)?;
+        }
+
+        let cameras = self.camera_nodes.iter().filter_map(|&(id, camera)| self.camera(&graph.world_matrix(id), camera)).collect();
+
+        Ok(GltfScene {
+            graph: graph,
+            cameras: cameras
+        })
+    }
+
+    fn import_node(&mut self, graph: &mut SceneGraph, parent: NodeId, node: &Node) -> io::Result<()> {
+        let name = node.name().map_or_else(|| format!("node_{}", node.index()), str::to_string);
+        let (translation, rotation, scale) = node.transform().decomposed();
+        let transform = Transform::new(
+            to_vec3(translation),
+            Quaternion::new(Vec3::new(rotation[0] as f64, rotation[1] as f64, rotation[2] as f64), rotation[3] as f64),
+            to_vec3(scale)
+        );
+
+        let hittable = match node.mesh() {
+            Some(mesh) => self.mesh(&mesh)?,
+            None => None
+        };
+
+        let id = match hittable {
+            Some(hittable) => graph.add_object(parent, &name, hittable, transform),
+            None => graph.add_node(parent, &name, transform)
+        };
+
+        if let Some(camera) = node.camera() {
+            self.camera_nodes.push((id, camera.index()));
+        }
+
+        for child in node.children() {
+            self.import_node(graph, id, &child)?;
+        }
+
+        Ok(())
+    }
+
+    fn mesh(&mut self, mesh: &gltf::Mesh) -> io::Result<Option<Arc<dyn Hittable>>> {
+        if let Some(hittable) = self.meshes.get(&mesh.index()) {
+            return Ok(hittable.clone())
+        }
+
+        let mut triangle_meshes = Vec::new();
+        for primitive in mesh.primitives().filter(|primitive| primitive.mode() == Mode::Triangles) {
+            if let Some(triangle_mesh) = self.primitive(&primitive)? {
+                triangle_meshes.push(Arc::new(triangle_mesh) as Arc<dyn Hittable>);
+            }
+        }
+
+        let hittable: Option<Arc<dyn Hittable>> = match triangle_meshes.len() {
+            0 => None,
+            1 => triangle_meshes.pop(),
+            _ => {
+                let mut list = HittableList::new();
+                for triangle_mesh in triangle_meshes {
+                    list.add(triangle_mesh);
+                }
+
+                Some(Arc::new(list))
+            }
+        };
+
+        self.meshes.insert(mesh.index(), hittable.clone());
+        Ok(hittable)
+    }
+
+    fn primitive(&mut self, primitive: &Primitive) -> io::Result<Option<TriangleMesh>> {
+        let buffers = &self.buffers;
+        let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| &data.0[..]));
+
+        let positions: Vec<Point3> = match reader.read_positions() {
+            Some(positions) => positions.map(to_vec3).collect(),
+            None => return Err(invalid_data("glTF primitive has no positions."))
+        };
+
+        let flat_indices: Vec<usize> = match reader.read_indices() {
+            Some(indices) => indices.into_u32().map(|index| index as usize).collect(),
+            None => (0..positions.len()).collect()
+        };
+
+        if flat_indices.iter().any(|&index| index >= positions.len()) {
+            return Err(invalid_data("glTF primitive index out of range."))
+        }
+
+        let indices: Vec<[usize; 3]> = flat_indices.chunks_exact(3).map(|triangle| [triangle[0], triangle[1], triangle[2]]).collect();
+        if indices.is_empty() {
+            return Ok(None)
+        }
+
+        let normals: Option<Vec<Vec3>> = reader.read_normals().map(|normals| normals.map(to_vec3).collect());
+        let uvs: Option<Vec<(f64, f64)>> = reader.read_tex_coords(0).map(|uvs| uvs.into_f32().map(|[u, v]| (u as f64, 1.0 - v as f64)).collect());
+        let colors: Option<Vec<Color>> = reader.read_colors(0).map(|colors| colors.into_rgb_f32().map(to_vec3).collect());
+
+        let material = self.material(&primitive.material(), colors.is_some());
+        let mut mesh = TriangleMesh::new(positions, indices, material);
+        let vertex_count = mesh.vertex_count();
+
+        if let Some(normals) = normals.filter(|normals| normals.len() == vertex_count) {
+            mesh = mesh.with_normals(normals);
+        }
+
+        if let Some(uvs) = uvs.filter(|uvs| uvs.len() == vertex_count) {
+            mesh = mesh.with_uvs(uvs);
+        }
+
+        if let Some(colors) = colors.filter(|colors| colors.len() == vertex_count) {
+            mesh = mesh.with_colors(colors);
+        }
+
+        Ok(Some(mesh))
+    }
+
+    fn material(&mut self, material: &gltf::Material, vertex_colors: bool) -> Arc<dyn Material> {
+        let key = (material.index(), vertex_colors);
+        if let Some(material) = self.materials.get(&key) {
+            return material.clone()
+        }
+
+        let pbr = material.pbr_metallic_roughness();
+        let [red, green, blue, _] = pbr.base_color_factor();
+        let mut albedo = self.texture(pbr.base_color_texture(), Color::new(red as f64, green as f64, blue as f64));
+
+        if vertex_colors {
+            albedo = Arc::new(VertexColor::new(albedo));
+        }
+
+        let emissive = to_vec3(material.emissive_factor()) * material.emissive_strength().unwrap_or(1.0) as f64;
+        let transmission = material.transmission().map_or(0.0, |transmission| transmission.transmission_factor());
+
+        let converted: Arc<dyn Material> = if emissive.squared_length() > 0.0 {
+            Arc::new(DiffuseLight::new(self.texture(material.emissive_texture(), emissive)))
+        } else if transmission >= 0.5 {
+            Arc::new(Dieletric::new(material.ior().unwrap_or(1.5) as f64))
+        } else if pbr.metallic_factor() >= 0.5 {
+            Arc::new(Metal::new(albedo, pbr.roughness_factor() as f64))
+        } else {
+            Arc::new(Lambertian::new(albedo))
+        };
+
+        self.materials.insert(key, converted.clone());
+        converted
+    }
+
+    fn texture(&self, info: Option<gltf::texture::Info>, factor: Color) -> Arc<dyn Texture> {
+        match info.and_then(|info| self.images.get(info.texture().source().index())) {
+            Some(data) => {
+                let mut image = to_image(data);
+                for pixel in image.buffer.iter_mut() {
+                    *pixel *= factor;
+                }
+
+                Arc::new(ImageTexture::new(Arc::new(image)))
+            },
+            None => Arc::new(SolidColor::new(factor))
+        }
+    }
+
+    fn camera(&self, world_matrix: &Matrix4, index: usize) -> Option<Camera> {
+        let camera = self.document.cameras().nth(index)?;
+
+        match camera.projection() {
+            Projection::Perspective(perspective) => {
+                let position = world_matrix.transform_point(Point3::zero());
+                let forward = world_matrix.transform_vector(-Vec3::front());
+                let up = world_matrix.transform_vector(Vec3::up());
+                let aspect_ratio = perspective.aspect_ratio().map_or(self.aspect_ratio, |aspect_ratio| aspect_ratio as f64);
+
+                Some(Camera::new(position, position + forward, up, perspective.yfov() as f64, aspect_ratio, 0.0, 1.0, 0.0, 1.0))
+            },
+            Projection::Orthographic(_) => None
+        }
+    }
+}
+
+fn to_vec3(v: [f32; 3]) -> Vec3 {
+    Vec3::new(v[0] as f64, v[1] as f64, v[2] as f64)
+}
+
+fn to_image(data: &ImageData) -> Image {
+    let (channels, bytes_per_channel) = match data.format {
+        Format::R8 => (1, 1),
+        Format::R8G8 => (2, 1),
+        Format::R8G8B8 => (3, 1),
+        Format::R8G8B8A8 => (4, 1),
+        Format::R16 => (1, 2),
+        Format::R16G16 => (2, 2),
+        Format::R16G16B16 => (3, 2),
+        Format::R16G16B16A16 => (4, 2),
+        Format::R32G32B32FLOAT => (3, 4),
+        Format::R32G32B32A32FLOAT => (4, 4)
+    };
+
+    let channel = |offset: usize| -> f64 {
+        let bytes = &data.pixels[offset..offset + bytes_per_channel];
+        match bytes_per_channel {
+            1 => bytes[0] as f64 / 255.0,
+            2 => u16::from_ne_bytes([bytes[0], bytes[1]]) as f64 / 65535.0,
+            _ => f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64
+        }
+    };
+
+    let mut image = Image::new(data.width as usize, data.height as usize);
+    let stride = channels * bytes_per_channel;
+
+    for (index, pixel) in image.buffer.iter_mut().enumerate() {
+        let offset = index * stride;
+        *pixel = match channels {
+            1 | 2 => Color::new(channel(offset), channel(offset), channel(offset)),
+            _ => Color::new(channel(offset), channel(offset + bytes_per_channel), channel(offset + 2 * bytes_per_channel))
+        };
+    }
+
+    image
+}
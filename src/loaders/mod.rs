@@ -0,0 +1,8 @@
+pub mod ply;
+pub use self::ply::{read_ply, parse_ply};
+
+pub mod stl;
+pub use self::stl::{read_stl, parse_stl};
+
+pub mod gltf;
+pub use self::gltf::{GltfScene, read_gltf, parse_gltf};
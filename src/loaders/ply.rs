@@ -0,0 +1,311 @@
+use crate::structures::{Vec3, Point3, Color};
+use crate::hittables::TriangleMesh;
+use crate::materials::Material;
+use crate::rendering::serialization::invalid_data;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::sync::Arc;
+
+#[derive(PartialEq, Clone, Copy)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64
+}
+
+impl Scalar {
+    fn parse(name: &str) -> io::Result<Self> {
+        match name {
+            "char" | "int8" => Ok(Scalar::I8),
+            "uchar" | "uint8" => Ok(Scalar::U8),
+            "short" | "int16" => Ok(Scalar::I16),
+            "ushort" | "uint16" => Ok(Scalar::U16),
+            "int" | "int32" => Ok(Scalar::I32),
+            "uint" | "uint32" => Ok(Scalar::U32),
+            "float" | "float32" => Ok(Scalar::F32),
+            "double" | "float64" => Ok(Scalar::F64),
+            _ => Err(invalid_data(&format!("Unknown PLY property type {}.", name)))
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Scalar::I8 | Scalar::U8 => 1,
+            Scalar::I16 | Scalar::U16 => 2,
+            Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+            Scalar::F64 => 8
+        }
+    }
+
+    fn color_scale(&self) -> f64 {
+        match self {
+            Scalar::U8 => 1.0 / 255.0,
+            Scalar::U16 => 1.0 / 65535.0,
+            _ => 1.0
+        }
+    }
+}
+
+enum Property {
+    Scalar(String, Scalar),
+    List(String, Scalar, Scalar)
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>
+}
+
+struct Body {
+    bytes: Vec<u8>,
+    position: usize,
+    format: Format
+}
+
+impl Body {
+    fn read(&mut self, scalar: Scalar) -> io::Result<f64> {
+        match self.format {
+            Format::Ascii => self.read_token(),
+            _ => self.read_binary(scalar)
+        }
+    }
+
+    fn read_index(&mut self, scalar: Scalar) -> io::Result<usize> {
+        let value = self.read(scalar)?;
+
+        match value >= 0.0 && value.fract() == 0.0 && value <= u32::MAX as f64 {
+            true => Ok(value as usize),
+            false => Err(invalid_data("PLY list count or index is not a non-negative integer."))
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    fn read_token(&mut self) -> io::Result<f64> {
+        while self.position < self.bytes.len() && self.bytes[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+
+        let start = self.position;
+        while self.position < self.bytes.len() && !self.bytes[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.position]).ok()
+            .and_then(|token| token.parse::<f64>().ok())
+            .ok_or_else(|| invalid_data("Malformed or truncated PLY value."))
+    }
+
+    fn read_binary(&mut self, scalar: Scalar) -> io::Result<f64> {
+        let size = scalar.size();
+
+        if self.position + size > self.bytes.len() {
+            return Err(invalid_data("Truncated binary PLY body."))
+        }
+
+        let mut buffer = [0u8; 8];
+        buffer[..size].copy_from_slice(&self.bytes[self.position..self.position + size]);
+        if self.format == Format::BinaryBigEndian {
+            buffer[..size].reverse();
+        }
+        self.position += size;
+
+        Ok(match scalar {
+            Scalar::I8 => buffer[0] as i8 as f64,
+            Scalar::U8 => buffer[0] as f64,
+            Scalar::I16 => i16::from_le_bytes([buffer[0], buffer[1]]) as f64,
+            Scalar::U16 => u16::from_le_bytes([buffer[0], buffer[1]]) as f64,
+            Scalar::I32 => i32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]) as f64,
+            Scalar::U32 => u32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]) as f64,
+            Scalar::F32 => f32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]) as f64,
+            Scalar::F64 => f64::from_le_bytes(buffer)
+        })
+    }
+}
+
+pub fn read_ply(path: &str, material: Arc<dyn Material>) -> io::Result<TriangleMesh> {
+    parse_ply(BufReader::new(File::open(path)?), material)
+}
+
+pub fn parse_ply<R: BufRead>(reader: R, material: Arc<dyn Material>) -> io::Result<TriangleMesh> {
+    let mut reader = reader;
+    let (format, elements) = read_header(&mut reader)?;
+
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let mut body = Body {
+        bytes: bytes,
+        position: 0,
+        format: format
+    };
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut colors = Vec::new();
+    let mut indices = Vec::new();
+
+    for element in &elements {
+        let has = |names: &[&str]| element.properties.iter().any(|property| matches!(property, Property::Scalar(name, _) if names.contains(&name.as_str())));
+
+        let is_vertex = element.name == "vertex";
+        let has_normals = is_vertex && has(&["nx"]);
+        let has_uvs = is_vertex && has(&["u", "s", "texture_u", "texture_s"]);
+        let has_colors = is_vertex && has(&["red"]);
+
+        for _ in 0..element.count {
+            let mut position = Point3::zero();
+            let mut normal = Vec3::zero();
+            let mut uv = (0.0, 0.0);
+            let mut color = Color::new(1.0, 1.0, 1.0);
+
+            for property in &element.properties {
+                match property {
+                    Property::Scalar(name, scalar) => {
+                        let value = body.read(*scalar)?;
+
+                        if is_vertex {
+                            match name.as_str() {
+                                "x" => position.x = value,
+                                "y" => position.y = value,
+                                "z" => position.z = value,
+                                "nx" => normal.x = value,
+                                "ny" => normal.y = value,
+                                "nz" => normal.z = value,
+                                "u" | "s" | "texture_u" | "texture_s" => uv.0 = value,
+                                "v" | "t" | "texture_v" | "texture_t" => uv.1 = value,
+                                "red" => color.x = value * scalar.color_scale(),
+                                "green" => color.y = value * scalar.color_scale(),
+                                "blue" => color.z = value * scalar.color_scale(),
+                                _ => {}
+                            }
+                        }
+                    },
+                    Property::List(name, count_scalar, item_scalar) => {
+                        let count = body.read_index(*count_scalar)?;
+                        let item_size = match body.format {
+                            Format::Ascii => 1,
+                            _ => item_scalar.size()
+                        };
+
+                        if count > body.remaining() / item_size {
+                            return Err(invalid_data("PLY list count exceeds the remaining body size."))
+                        }
+
+                        let mut items = Vec::with_capacity(count);
+                        for _ in 0..count {
+                            items.push(body.read_index(*item_scalar)?);
+                        }
+
+                        if element.name == "face" && (name == "vertex_indices" || name == "vertex_index") {
+                            if items.len() < 3 {
+                                return Err(invalid_data("PLY face with fewer than three vertices."))
+                            }
+
+                            indices.extend((1..items.len() - 1).map(|i| [items[0], items[i], items[i + 1]]));
+                        }
+                    }
+                }
+            }
+
+            if is_vertex {
+                positions.push(position);
+                if has_normals {
+                    normals.push(normal);
+                }
+                if has_uvs {
+                    uvs.push(uv);
+                }
+                if has_colors {
+                    colors.push(color);
+                }
+            }
+        }
+    }
+
+    if indices.is_empty() {
+        return Err(invalid_data("PLY file contains no faces."))
+    }
+
+    if indices.iter().flatten().any(|&index| index >= positions.len()) {
+        return Err(invalid_data("PLY face index out of range."))
+    }
+
+    let mut mesh = TriangleMesh::new(positions, indices, material);
+
+    if !normals.is_empty() {
+        mesh = mesh.with_normals(normals);
+    }
+
+    if !uvs.is_empty() {
+        mesh = mesh.with_uvs(uvs);
+    }
+
+    if !colors.is_empty() {
+        mesh = mesh.with_colors(colors);
+    }
+
+    Ok(mesh)
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> io::Result<(Format, Vec<Element>)> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    if line.trim() != "ply" {
+        return Err(invalid_data("Not a PLY file."))
+    }
+
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid_data("PLY header is missing end_header."))
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            ["format", "ascii", _] => format = Some(Format::Ascii),
+            ["format", "binary_little_endian", _] => format = Some(Format::BinaryLittleEndian),
+            ["format", "binary_big_endian", _] => format = Some(Format::BinaryBigEndian),
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count.parse().map_err(|_| invalid_data("Invalid PLY element count."))?,
+                properties: Vec::new()
+            }),
+            ["property", "list", count, item, name] => elements.last_mut()
+                .ok_or_else(|| invalid_data("PLY property outside of an element."))?
+                .properties.push(Property::List(name.to_string(), Scalar::parse(count)?, Scalar::parse(item)?)),
+            ["property", scalar, name] => elements.last_mut()
+                .ok_or_else(|| invalid_data("PLY property outside of an element."))?
+                .properties.push(Property::Scalar(name.to_string(), Scalar::parse(scalar)?)),
+            ["end_header"] => break,
+            ["comment", ..] | ["obj_info", ..] | [] => {},
+            _ => return Err(invalid_data(&format!("Unexpected PLY header line: {}", line.trim())))
+        }
+    }
+
+    match format {
+        Some(format) => Ok((format, elements)),
+        None => Err(invalid_data("PLY header is missing its format."))
+    }
+}
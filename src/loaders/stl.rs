@@ -0,0 +1,71 @@
+use crate::structures::Point3;
+use crate::hittables::TriangleMesh;
+use crate::materials::Material;
+use crate::rendering::serialization::invalid_data;
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::sync::Arc;
+
+const HEADER_SIZE: usize = 80;
+const TRIANGLE_SIZE: usize = 50;
+
+pub fn read_stl(path: &str, material: Arc<dyn Material>) -> io::Result<TriangleMesh> {
+    parse_stl(&fs::read(path)?, material)
+}
+
+pub fn parse_stl(bytes: &[u8], material: Arc<dyn Material>) -> io::Result<TriangleMesh> {
+    let corners = match is_binary(bytes) {
+        true => parse_binary(bytes),
+        false => parse_ascii(bytes)?
+    };
+
+    if corners.is_empty() || corners.len() % 3 != 0 {
+        return Err(invalid_data("STL file contains no complete triangles."))
+    }
+
+    let mut positions = Vec::new();
+    let mut lookup = HashMap::new();
+    let welded: Vec<usize> = corners.iter().map(|corner| {
+        *lookup.entry([corner.x.to_bits(), corner.y.to_bits(), corner.z.to_bits()]).or_insert_with(|| {
+            positions.push(*corner);
+            positions.len() - 1
+        })
+    }).collect();
+
+    let indices = welded.chunks(3).map(|triangle| [triangle[0], triangle[1], triangle[2]]).collect();
+
+    Ok(TriangleMesh::new(positions, indices, material))
+}
+
+fn is_binary(bytes: &[u8]) -> bool {
+    if bytes.len() < HEADER_SIZE + 4 {
+        return false
+    }
+
+    let count = u32::from_le_bytes([bytes[HEADER_SIZE], bytes[HEADER_SIZE + 1], bytes[HEADER_SIZE + 2], bytes[HEADER_SIZE + 3]]) as usize;
+    bytes.len() == HEADER_SIZE + 4 + count * TRIANGLE_SIZE || !bytes.starts_with(b"solid")
+}
+
+fn parse_binary(bytes: &[u8]) -> Vec<Point3> {
+    bytes[HEADER_SIZE + 4..].chunks_exact(TRIANGLE_SIZE).flat_map(|triangle| {
+        let read_f32 = move |offset: usize| f32::from_le_bytes([triangle[offset], triangle[offset + 1], triangle[offset + 2], triangle[offset + 3]]) as f64;
+        (1..4).map(move |corner| Point3::new(read_f32(corner * 12), read_f32(corner * 12 + 4), read_f32(corner * 12 + 8)))
+    }).collect()
+}
+
+fn parse_ascii(bytes: &[u8]) -> io::Result<Vec<Point3>> {
+    let text = std::str::from_utf8(bytes).map_err(|_| invalid_data("ASCII STL is not valid UTF-8."))?;
+    let mut tokens = text.split_whitespace();
+    let mut corners = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        if token == "vertex" {
+            let mut coordinate = || tokens.next().and_then(|value| value.parse::<f64>().ok()).ok_or_else(|| invalid_data("Malformed STL vertex."));
+            corners.push(Point3::new(coordinate()?, coordinate()?, coordinate()?));
+        }
+    }
+
+    Ok(corners)
+}
@@ -1,7 +1,8 @@
-use crate::structures::{Color, Vec3, Ray, HitRecord};
+use crate::structures::{Color, Vec3, Ray, RayVisibility, HitRecord};
 use crate::materials::Material;
+use crate::random::thread_rng;
 
-use rand::prelude::{thread_rng, Rng};
+use rand::Rng;
 
 pub struct Dieletric {
     pub refraction_index: f64
@@ -37,14 +38,14 @@ impl Material for Dieletric {
         let sin_theta = f64::sqrt(1.0 - f64::powi(cos_theta, 2));
         let cant_refract = sin_theta * refraction_ratio > 1.0;
   
-        let scatter_direction = if cant_refract || Dieletric::reflectance(cos_theta, refraction_ratio) > rng.gen() {
-            Vec3::reflect(&ray.direction, &normal)
+        let (scatter_direction, kind) = if cant_refract || Dieletric::reflectance(cos_theta, refraction_ratio) > rng.gen() {
+            (Vec3::reflect(&ray.direction, &normal), RayVisibility::GLOSSY)
         } else {
-            Vec3::refract(&ray.direction, &normal, eta_in, eta_out)
+            (Vec3::refract(&ray.direction, &normal, eta_in, eta_out), RayVisibility::TRANSMISSION)
         };
 
         
-        let scattered_ray = Ray::with_time(hit.point, scatter_direction, ray.time);
+        let scattered_ray = Ray::with_time(hit.point, scatter_direction, ray.time).with_kind(kind);
         
         Some((scattered_ray, attenuation))
     }
@@ -0,0 +1,123 @@
+use crate::textures::Texture;
+use crate::structures::{Color, Vec3, Ray, RayVisibility, HitRecord};
+use crate::materials::Material;
+use crate::random::thread_rng;
+
+use std::sync::Arc;
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+pub struct Hair {
+    pub color: Arc<dyn Texture>,
+    pub refraction_index: f64,
+    pub longitudinal_roughness: f64,
+    pub azimuthal_roughness: f64,
+    pub cuticle_angle: f64
+}
+
+impl Hair {
+    pub fn new(color: Arc<dyn Texture>, longitudinal_roughness: f64, azimuthal_roughness: f64) -> Self {
+        Self {
+            color: color,
+            refraction_index: 1.55,
+            longitudinal_roughness: longitudinal_roughness,
+            azimuthal_roughness: azimuthal_roughness,
+            cuticle_angle: 2.0f64.to_radians()
+        }
+    }
+
+    pub fn with_refraction_index(self, refraction_index: f64) -> Self {
+        Self {
+            refraction_index: refraction_index,
+            ..self
+        }
+    }
+
+    pub fn with_cuticle_angle(self, cuticle_angle: f64) -> Self {
+        Self {
+            cuticle_angle: cuticle_angle,
+            ..self
+        }
+    }
+
+    fn reflectance(cosine: f64, refraction_index: f64) -> f64 {
+        let r0 = ((1.0 - refraction_index) / (1.0 + refraction_index)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    }
+
+    fn sample_gaussian<R: Rng>(rng: &mut R, deviation: f64) -> f64 {
+        let u_1: f64 = rng.gen_range(f64::EPSILON..1.0);
+        let u_2: f64 = rng.gen();
+        deviation * f64::sqrt(-2.0 * u_1.ln()) * f64::cos(2.0 * PI * u_2)
+    }
+}
+
+impl Material for Hair {
+    fn scatter(&self, ray: &Ray, hit: &HitRecord) -> Option<(Ray, Color)> {
+        let mut rng = thread_rng();
+
+        let facing_normal = hit.get_facing_normal(ray);
+        let tangent = match hit.tangent {
+            Some(tangent) => tangent,
+            None => {
+                let helper = match facing_normal.x.abs() > 0.9 {
+                    true => Vec3::up(),
+                    false => Vec3::right()
+                };
+                Vec3::cross(&facing_normal, &helper).normalized()
+            }
+        };
+
+        let normal = match facing_normal - Vec3::dot(&facing_normal, &tangent) * tangent {
+            normal if normal.squared_length() > 1e-12 => normal.normalized(),
+            _ => return None
+        };
+        let binormal = Vec3::cross(&tangent, &normal);
+
+        let incoming = -ray.direction.normalized();
+        let theta_in = f64::asin(f64::clamp(Vec3::dot(&incoming, &tangent), -1.0, 1.0));
+        let phi_in = f64::atan2(Vec3::dot(&incoming, &binormal), Vec3::dot(&incoming, &normal));
+
+        let fresnel = Hair::reflectance(f64::clamp(theta_in.cos() * phi_in.cos().abs(), 0.0, 1.0), self.refraction_index);
+        let transmittance = self.color.value_at_hit(hit);
+
+        let lobes = [
+            Color::new(fresnel, fresnel, fresnel),
+            (1.0 - fresnel).powi(2) * transmittance,
+            (1.0 - fresnel).powi(2) * fresnel * transmittance * transmittance
+        ];
+        let energies = lobes.map(|lobe| (lobe.x + lobe.y + lobe.z) / 3.0);
+        let total: f64 = energies.iter().sum();
+
+        if total <= 0.0 {
+            return None
+        }
+
+        let choice = rng.gen_range(0.0..total);
+        let lobe = match choice {
+            choice if choice < energies[0] => 0,
+            choice if choice < energies[0] + energies[1] => 1,
+            _ => 2
+        };
+
+        let (tilt, longitudinal, azimuth, azimuthal) = match lobe {
+            0 => (2.0 * self.cuticle_angle, self.longitudinal_roughness, -phi_in, self.azimuthal_roughness),
+            1 => (-self.cuticle_angle, 0.5 * self.longitudinal_roughness, phi_in + PI, self.azimuthal_roughness),
+            _ => (-4.0 * self.cuticle_angle, 2.0 * self.longitudinal_roughness, -phi_in, 2.0 * self.azimuthal_roughness)
+        };
+
+        let theta_out = f64::clamp(-theta_in + tilt + Hair::sample_gaussian(&mut rng, longitudinal), -0.5 * PI, 0.5 * PI);
+        let phi_out = azimuth + Hair::sample_gaussian(&mut rng, azimuthal * PI);
+
+        let direction = theta_out.sin() * tangent + theta_out.cos() * (phi_out.cos() * normal + phi_out.sin() * binormal);
+        let attenuation = lobes[lobe] * (total / energies[lobe]);
+
+        let kind = match lobe {
+            1 => RayVisibility::TRANSMISSION,
+            _ => RayVisibility::GLOSSY
+        };
+
+        Some((Ray::with_time(hit.point, direction, ray.time).with_kind(kind), attenuation))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::structures::{Color, Vec3, Ray, HitRecord};
+use crate::structures::{Color, Vec3, Ray, RayVisibility, HitRecord};
 use crate::textures::Texture;
 use crate::materials::Material;
 
@@ -20,8 +20,8 @@ impl Material for Lambertian {
     fn scatter(&self, ray: &Ray, hit: &HitRecord) -> Option<(Ray, Color)> {
         let normal = hit.get_facing_normal(ray);
         let scatter_direction = Vec3::random_in_hemisphere(&normal);
-        let scattered_ray = Ray::with_time(hit.point, scatter_direction, ray.time);
-        let attenuation = self.albedo.value(hit.u, hit.v, hit.point);
+        let scattered_ray = Ray::with_time(hit.point, scatter_direction, ray.time).with_kind(RayVisibility::DIFFUSE);
+        let attenuation = self.albedo.value_at_hit(hit);
         Some((scattered_ray, attenuation))
     }
 }
\ No newline at end of file
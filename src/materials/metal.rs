@@ -1,5 +1,5 @@
 use crate::textures::Texture;
-use crate::structures::{Color, Vec3, Ray, HitRecord};
+use crate::structures::{Color, Vec3, Ray, RayVisibility, HitRecord};
 use crate::materials::Material;
 
 use std::sync::Arc;
@@ -23,8 +23,8 @@ impl Material for Metal {
         let normal = hit.get_facing_normal(ray);
         let reflected = Vec3::reflect(&ray.direction, &normal);
         let scatter_direction = reflected + self.fuzziness * Vec3::random_in_unit_sphere();
-        let scattered_ray = Ray::with_time(hit.point, scatter_direction, ray.time);
-        let attenuation = self.albedo.value(hit.u, hit.v, hit.point);
+        let scattered_ray = Ray::with_time(hit.point, scatter_direction, ray.time).with_kind(RayVisibility::GLOSSY);
+        let attenuation = self.albedo.value_at_hit(hit);
 
         if Vec3::dot(&scattered_ray.direction, &normal) > 0.0 {
             Some((scattered_ray, attenuation))
@@ -10,6 +10,9 @@ pub use self::metal::Metal;
 pub mod dieletric;
 pub use self::dieletric::Dieletric;
 
+pub mod hair;
+pub use self::hair::Hair;
+
 pub mod diffuse_light;
 pub use self::diffuse_light::DiffuseLight;
 
@@ -1,4 +1,4 @@
-use crate::structures::{ Vec3, Ray, Color, HitRecord };
+use crate::structures::{ Vec3, Ray, RayVisibility, Color, HitRecord };
 use crate::materials::Material;
 use crate::textures::Texture;
 
@@ -11,8 +11,8 @@ pub struct Isotropic {
 impl Material for Isotropic {
 
     fn scatter(&self, ray: &Ray, hit: &HitRecord) -> Option<(Ray, Color)> { 
-        let scattered_ray = Ray::with_time(hit.point, Vec3::random_in_unit_sphere(), ray.time);
-        let attenuation = self.albedo.value(hit.u, hit.v, hit.point);
+        let scattered_ray = Ray::with_time(hit.point, Vec3::random_in_unit_sphere(), ray.time).with_kind(RayVisibility::DIFFUSE);
+        let attenuation = self.albedo.value_at_hit(hit);
         Some((scattered_ray, attenuation))
     }
 
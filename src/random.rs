@@ -0,0 +1,44 @@
+use rand::{Error, RngCore, SeedableRng};
+use rand::rngs::StdRng;
+
+use std::cell::RefCell;
+
+thread_local! {
+    static THREAD_RNG: RefCell<StdRng> = RefCell::new(StdRng::from_entropy());
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ThreadRng;
+
+pub fn thread_rng() -> ThreadRng {
+    ThreadRng
+}
+
+pub fn reseed(seed: u64) {
+    THREAD_RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+pub fn mix_seed(seed: u64, stream: u64) -> u64 {
+    let mut z = seed ^ stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl RngCore for ThreadRng {
+    fn next_u32(&mut self) -> u32 {
+        THREAD_RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        THREAD_RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        THREAD_RNG.with(|rng| rng.borrow_mut().fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        THREAD_RNG.with(|rng| rng.borrow_mut().try_fill_bytes(dest))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::structures::{Point3, Vec3, Ray};
+use crate::random::thread_rng;
 
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
 pub struct Camera {
     pub vertical_fov: f64,
@@ -35,6 +36,10 @@ impl Camera {
         }
     }
 
+    pub fn pixel_size(&self, image_height: usize) -> f64 {
+        2.0 * (self.vertical_fov / 2.0).tan() / image_height as f64
+    }
+
     pub fn get_ray(&self, u: f64, v: f64) -> Ray {
         let mut rng = thread_rng();
 
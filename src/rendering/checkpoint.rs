@@ -0,0 +1,105 @@
+use crate::rendering::{Film, Filter, Integrator, LightLinking, RenderParams};
+use crate::rendering::serialization::{write_u32, write_u64, read_u32, read_u64, invalid_data};
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+
+const MAGIC: &[u8; 4] = b"RTCK";
+const VERSION: u32 = 3;
+const PIXEL_SIZE: u64 = 32;
+
+#[derive(Clone, Debug)]
+pub struct CheckpointParams {
+    pub path: String,
+    pub interval: u32,
+    pub resume: bool
+}
+
+impl CheckpointParams {
+    pub fn new(path: &str, interval: u32, resume: bool) -> Self {
+        Self {
+            path: path.to_string(),
+            interval: interval,
+            resume: resume
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Checkpoint {
+    pub seed: u64,
+    pub max_ray_depth: u32,
+    pub filter: Filter,
+    pub integrator: Integrator,
+    pub light_linking: LightLinking,
+    pub completed_passes: u32,
+    pub film: Film
+}
+
+impl Checkpoint {
+    pub fn is_compatible(&self, params: &RenderParams) -> bool {
+        self.seed == params.seed &&
+        self.max_ray_depth == params.max_ray_depth &&
+        self.filter == params.filter &&
+        self.integrator == params.integrator &&
+        self.light_linking == params.light_linking &&
+        self.film.width == params.image_width &&
+        self.film.height == params.image_height &&
+        self.completed_passes <= params.num_samples
+    }
+
+    pub fn write(&self, path: &str) -> io::Result<()> {
+        let temporary_path = format!("{}.tmp", path);
+
+        {
+            let mut writer = BufWriter::new(File::create(&temporary_path)?);
+            self.write_to(&mut writer)?;
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+        }
+
+        fs::rename(&temporary_path, path)
+    }
+
+    pub fn read(path: &str) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let max_pixels = file.metadata()?.len() / PIXEL_SIZE;
+
+        Self::read_from(&mut BufReader::new(file), max_pixels as usize)
+    }
+
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        write_u32(writer, VERSION)?;
+        write_u64(writer, self.seed)?;
+        write_u32(writer, self.max_ray_depth)?;
+        self.filter.write_to(writer)?;
+        self.integrator.write_to(writer)?;
+        self.light_linking.write_to(writer)?;
+        write_u32(writer, self.completed_passes)?;
+        self.film.write_to(writer)
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R, max_pixels: usize) -> io::Result<Self> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != MAGIC {
+            return Err(invalid_data("not a render checkpoint"))
+        }
+
+        if read_u32(reader)? != VERSION {
+            return Err(invalid_data("unsupported checkpoint version"))
+        }
+
+        Ok(Self {
+            seed: read_u64(reader)?,
+            max_ray_depth: read_u32(reader)?,
+            filter: Filter::read_from(reader)?,
+            integrator: Integrator::read_from(reader)?,
+            light_linking: LightLinking::read_from(reader)?,
+            completed_passes: read_u32(reader)?,
+            film: Film::read_from(reader, max_pixels)?
+        })
+    }
+}
@@ -0,0 +1,167 @@
+use crate::structures::Image;
+use crate::rendering::{RenderHandle, RenderParams};
+use crate::rendering::distributed::protocol::Message;
+
+use std::collections::VecDeque;
+use std::io::{self, BufReader, BufWriter};
+use std::net::TcpStream;
+use std::ops::Range;
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct DistributedParams {
+    pub workers: Vec<String>,
+    pub passes_per_task: u32,
+    pub timeout: Duration
+}
+
+impl DistributedParams {
+    pub fn new(workers: Vec<String>) -> Self {
+        Self {
+            workers: workers,
+            passes_per_task: 4,
+            timeout: Duration::from_secs(600)
+        }
+    }
+}
+
+struct Schedule {
+    queue: VecDeque<Range<u32>>,
+    in_flight: usize,
+    failures: Vec<String>
+}
+
+impl Schedule {
+    fn is_finished(&self) -> bool {
+        self.queue.is_empty() && self.in_flight == 0
+    }
+}
+
+struct Coordination<'a, T> {
+    scene: &'a [u8],
+    params: &'a RenderParams,
+    timeout: Duration,
+    handle: &'a RenderHandle,
+    schedule: Mutex<Schedule>,
+    changed: Condvar,
+    progress: Mutex<T>
+}
+
+pub fn render_distributed<'a, T: FnMut(u32, u32) + Send + 'a>(scene: &[u8], params: &RenderParams, distributed: &DistributedParams, handle: &RenderHandle, progress: T) -> io::Result<Image> {
+    let passes_per_task = u32::max(distributed.passes_per_task, 1);
+    let first_pass = handle.completed_passes();
+
+    let mut queue = VecDeque::new();
+    let mut pass = first_pass;
+    while pass < params.num_samples {
+        let last = u32::min(pass + passes_per_task, params.num_samples);
+        queue.push_back(pass..last);
+        pass = last;
+    }
+
+    let coordination = Coordination {
+        scene: scene,
+        params: params,
+        timeout: distributed.timeout,
+        handle: handle,
+        schedule: Mutex::new(Schedule {
+            queue: queue,
+            in_flight: 0,
+            failures: vec![]
+        }),
+        changed: Condvar::new(),
+        progress: Mutex::new(progress)
+    };
+
+    thread::scope(|scope| {
+        for address in &distributed.workers {
+            let coordination = &coordination;
+
+            scope.spawn(move || {
+                if let Err(error) = coordination.run_connection(address) {
+                    let mut schedule = coordination.schedule.lock().unwrap();
+                    schedule.failures.push(format!("{}: {}", address, error));
+                    coordination.changed.notify_all();
+                }
+            });
+        }
+    });
+
+    let schedule = coordination.schedule.into_inner().unwrap();
+
+    if !schedule.is_finished() && !handle.is_cancelled() {
+        let reason = format!("all workers failed before the render finished ({})", schedule.failures.join("; "));
+        return Err(io::Error::other(reason))
+    }
+
+    Ok(handle.current_image())
+}
+
+impl<'a, T: FnMut(u32, u32)> Coordination<'a, T> {
+    fn run_connection(&self, address: &str) -> io::Result<()> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = BufWriter::new(stream);
+
+        Message::Setup { scene: self.scene.to_vec(), params: self.params.clone() }.write_to(&mut writer)?;
+
+        loop {
+            let task = match self.next_task() {
+                Some(task) => task,
+                None => return Message::Finish.write_to(&mut writer)
+            };
+
+            let result = run_task(&mut reader, &mut writer, &task, self.params, self.handle);
+
+            let mut schedule = self.schedule.lock().unwrap();
+            schedule.in_flight -= 1;
+            if result.is_err() {
+                schedule.queue.push_front(task);
+            }
+            self.changed.notify_all();
+            drop(schedule);
+
+            result?;
+
+            let mut progress = self.progress.lock().unwrap();
+            (*progress)(self.handle.completed_passes(), self.params.num_samples);
+        }
+    }
+
+    fn next_task(&self) -> Option<Range<u32>> {
+        let mut schedule = self.schedule.lock().unwrap();
+
+        loop {
+            if self.handle.is_cancelled() || schedule.is_finished() {
+                return None
+            }
+
+            if let Some(task) = schedule.queue.pop_front() {
+                schedule.in_flight += 1;
+                return Some(task)
+            }
+
+            schedule = self.changed.wait_timeout(schedule, Duration::from_millis(100)).unwrap().0;
+        }
+    }
+}
+
+fn run_task(reader: &mut BufReader<TcpStream>, writer: &mut BufWriter<TcpStream>, task: &Range<u32>, params: &RenderParams, handle: &RenderHandle) -> io::Result<()> {
+    Message::Task { first_pass: task.start, pass_count: task.end - task.start }.write_to(writer)?;
+
+    match Message::read_from(reader, params.image_width * params.image_height)? {
+        Message::Result { first_pass, pass_count, film, statistics }
+            if first_pass == task.start && pass_count == task.end - task.start && film.width == params.image_width && film.height == params.image_height => {
+            handle.record_statistics(&statistics);
+            handle.accumulate(first_pass, pass_count, film);
+            Ok(())
+        },
+        Message::Failure { reason } => Err(io::Error::other(reason)),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected reply from worker"))
+    }
+}
@@ -0,0 +1,7 @@
+mod protocol;
+
+pub mod coordinator;
+pub use self::coordinator::{DistributedParams, render_distributed};
+
+pub mod worker;
+pub use self::worker::{SceneLoader, run_worker, serve_worker, serve_connection};
@@ -0,0 +1,109 @@
+use crate::rendering::{Film, Filter, Integrator, LightLinking, RenderParams, RenderStatistics};
+use crate::rendering::serialization::{write_u32, write_u64, read_u32, read_u64, invalid_data};
+
+use std::io::{self, Read, Write};
+
+const SETUP: u32 = 1;
+const TASK: u32 = 2;
+const FINISH: u32 = 3;
+const RESULT: u32 = 4;
+const FAILURE: u32 = 5;
+
+const MAX_PAYLOAD: u64 = 1 << 32;
+
+pub(crate) enum Message {
+    Setup { scene: Vec<u8>, params: RenderParams },
+    Task { first_pass: u32, pass_count: u32 },
+    Finish,
+    Result { first_pass: u32, pass_count: u32, film: Film, statistics: RenderStatistics },
+    Failure { reason: String }
+}
+
+impl Message {
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Message::Setup { scene, params } => {
+                write_u32(writer, SETUP)?;
+                write_bytes(writer, scene)?;
+                write_u64(writer, params.image_width as u64)?;
+                write_u64(writer, params.image_height as u64)?;
+                write_u32(writer, params.max_ray_depth)?;
+                write_u64(writer, params.seed)?;
+                params.filter.write_to(writer)?;
+                params.integrator.write_to(writer)?;
+                params.light_linking.write_to(writer)?;
+            },
+            Message::Task { first_pass, pass_count } => {
+                write_u32(writer, TASK)?;
+                write_u32(writer, *first_pass)?;
+                write_u32(writer, *pass_count)?;
+            },
+            Message::Finish => {
+                write_u32(writer, FINISH)?;
+            },
+            Message::Result { first_pass, pass_count, film, statistics } => {
+                write_u32(writer, RESULT)?;
+                write_u32(writer, *first_pass)?;
+                write_u32(writer, *pass_count)?;
+                film.write_to(writer)?;
+                statistics.write_to(writer)?;
+            },
+            Message::Failure { reason } => {
+                write_u32(writer, FAILURE)?;
+                write_bytes(writer, reason.as_bytes())?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R, film_pixels: usize) -> io::Result<Self> {
+        match read_u32(reader)? {
+            SETUP => {
+                let scene = read_bytes(reader)?;
+                let params = RenderParams {
+                    image_width: read_u64(reader)? as usize,
+                    image_height: read_u64(reader)? as usize,
+                    max_ray_depth: read_u32(reader)?,
+                    seed: read_u64(reader)?,
+                    filter: Filter::read_from(reader)?,
+                    integrator: Integrator::read_from(reader)?,
+                    light_linking: LightLinking::read_from(reader)?,
+                    ..Default::default()
+                };
+
+                Ok(Message::Setup { scene: scene, params: params })
+            },
+            TASK => Ok(Message::Task { first_pass: read_u32(reader)?, pass_count: read_u32(reader)? }),
+            FINISH => Ok(Message::Finish),
+            RESULT => Ok(Message::Result {
+                first_pass: read_u32(reader)?,
+                pass_count: read_u32(reader)?,
+                film: Film::read_from(reader, film_pixels)?,
+                statistics: RenderStatistics::read_from(reader)?
+            }),
+            FAILURE => {
+                let reason = String::from_utf8_lossy(&read_bytes(reader)?).into_owned();
+                Ok(Message::Failure { reason: reason })
+            },
+            _ => Err(invalid_data("unknown message"))
+        }
+    }
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    write_u64(writer, bytes.len() as u64)?;
+    writer.write_all(bytes)
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let length = read_u64(reader)?;
+
+    if length > MAX_PAYLOAD {
+        return Err(invalid_data("payload too large"))
+    }
+
+    let mut bytes = vec![0; length as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
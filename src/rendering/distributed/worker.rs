@@ -0,0 +1,63 @@
+use crate::hittables::Hittable;
+use crate::skyboxes::Skybox;
+use crate::rendering::{Camera, RenderHandle};
+use crate::rendering::render::render_passes;
+use crate::rendering::distributed::protocol::Message;
+
+use std::io::{self, BufReader, BufWriter};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+
+pub type SceneLoader = dyn Fn(&[u8]) -> io::Result<(Arc<dyn Hittable>, Arc<dyn Skybox>, Arc<Camera>)>;
+
+pub fn run_worker(address: &str, loader: &SceneLoader) -> io::Result<()> {
+    serve_worker(TcpListener::bind(address)?, loader)
+}
+
+pub fn serve_worker(listener: TcpListener, loader: &SceneLoader) -> io::Result<()> {
+    for stream in listener.incoming() {
+        if let Err(error) = serve_connection(stream?, loader) {
+            eprintln!("Worker connection closed: {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn serve_connection(stream: TcpStream, loader: &SceneLoader) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = BufWriter::new(stream);
+
+    let (scene, params) = match Message::read_from(&mut reader, 0)? {
+        Message::Setup { scene, params } => (scene, params),
+        _ => return Message::Failure { reason: "expected setup".to_string() }.write_to(&mut writer)
+    };
+
+    let (world, skybox, camera) = match loader(&scene) {
+        Ok(scene) => scene,
+        Err(error) => return Message::Failure { reason: error.to_string() }.write_to(&mut writer)
+    };
+
+    let handle = RenderHandle::new(&params);
+
+    loop {
+        match Message::read_from(&mut reader, 0)? {
+            Message::Task { first_pass, pass_count } => {
+                handle.reset(first_pass);
+                let passes = first_pass..(first_pass + pass_count);
+                render_passes(world.clone(), skybox.clone(), camera.clone(), &params, passes, &handle, |_| {});
+                let result = Message::Result {
+                    first_pass: first_pass,
+                    pass_count: pass_count,
+                    film: handle.current_film(),
+                    statistics: handle.take_statistics()
+                };
+
+                result.write_to(&mut writer)?;
+            },
+            Message::Finish => return Ok(()),
+            _ => return Message::Failure { reason: "unexpected message".to_string() }.write_to(&mut writer)
+        }
+    }
+}
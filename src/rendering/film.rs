@@ -0,0 +1,104 @@
+use crate::structures::{Color, Image};
+use crate::rendering::Filter;
+use crate::rendering::serialization::{write_u64, write_f64, write_color, read_u64, read_f64, read_color, invalid_data};
+
+use std::io::{self, Read, Write};
+
+#[derive(Clone)]
+pub struct Film {
+    pub width: usize,
+    pub height: usize,
+    pub radiance: Vec<Color>,
+    pub weights: Vec<f64>
+}
+
+impl Film {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width: width,
+            height: height,
+            radiance: vec![Color::zero(); width * height],
+            weights: vec![0.0; width * height]
+        }
+    }
+
+    pub fn add_sample(&mut self, filter: &Filter, fx: f64, fy: f64, color: Color) {
+        let radius = filter.radius();
+
+        let x0 = f64::max(f64::ceil(fx - 0.5 - radius), 0.0) as usize;
+        let y0 = f64::max(f64::ceil(fy - 0.5 - radius), 0.0) as usize;
+        let x1 = f64::min(f64::floor(fx - 0.5 + radius), self.width as f64 - 1.0);
+        let y1 = f64::min(f64::floor(fy - 0.5 + radius), self.height as f64 - 1.0);
+
+        if x1 < 0.0 || y1 < 0.0 {
+            return
+        }
+
+        for y in y0..=(y1 as usize) {
+            for x in x0..=(x1 as usize) {
+                let weight = filter.evaluate((x as f64 + 0.5) - fx, (y as f64 + 0.5) - fy);
+
+                if weight == 0.0 {
+                    continue
+                }
+
+                let index = y * self.width + x;
+                self.radiance[index] += weight * color;
+                self.weights[index] += weight;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: &Film) {
+        assert_eq!(self.width, other.width);
+        assert_eq!(self.height, other.height);
+
+        for i in 0..self.radiance.len() {
+            self.radiance[i] += other.radiance[i];
+            self.weights[i] += other.weights[i];
+        }
+    }
+
+    pub fn to_image(&self) -> Image {
+        let mut image = Image::new(self.width, self.height);
+
+        for i in 0..self.radiance.len() {
+            if self.weights[i] > 0.0 {
+                let color = self.radiance[i] / self.weights[i];
+                image.buffer[i] = Color::new(f64::max(color.x, 0.0), f64::max(color.y, 0.0), f64::max(color.z, 0.0));
+            }
+        }
+
+        image
+    }
+
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_u64(writer, self.width as u64)?;
+        write_u64(writer, self.height as u64)?;
+
+        for i in 0..self.radiance.len() {
+            write_color(writer, self.radiance[i])?;
+            write_f64(writer, self.weights[i])?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R, max_pixels: usize) -> io::Result<Self> {
+        let width = read_u64(reader)? as usize;
+        let height = read_u64(reader)? as usize;
+
+        if width.checked_mul(height).is_none_or(|pixels| pixels > max_pixels) {
+            return Err(invalid_data("film dimensions exceed the expected size"))
+        }
+
+        let mut film = Film::new(width, height);
+
+        for i in 0..film.radiance.len() {
+            film.radiance[i] = read_color(reader)?;
+            film.weights[i] = read_f64(reader)?;
+        }
+
+        Ok(film)
+    }
+}
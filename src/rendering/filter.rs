@@ -0,0 +1,139 @@
+use crate::rendering::serialization::{write_u32, write_f64, read_u32, read_f64, invalid_data};
+
+use std::f64::consts::PI;
+use std::io::{self, Read, Write};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Filter {
+    Box { radius: f64 },
+    Triangle { radius: f64 },
+    Gaussian { radius: f64, alpha: f64 },
+    Mitchell { radius: f64, b: f64, c: f64 },
+    Lanczos { radius: f64, tau: f64 },
+    BlackmanHarris { radius: f64 }
+}
+
+impl Filter {
+    pub fn box_filter(radius: f64) -> Self {
+        Filter::Box { radius: radius }
+    }
+
+    pub fn triangle(radius: f64) -> Self {
+        Filter::Triangle { radius: radius }
+    }
+
+    pub fn gaussian(radius: f64) -> Self {
+        Filter::Gaussian { radius: radius, alpha: 2.0 }
+    }
+
+    pub fn mitchell(radius: f64) -> Self {
+        Filter::Mitchell { radius: radius, b: 1.0 / 3.0, c: 1.0 / 3.0 }
+    }
+
+    pub fn lanczos(radius: f64) -> Self {
+        Filter::Lanczos { radius: radius, tau: 3.0 }
+    }
+
+    pub fn blackman_harris(radius: f64) -> Self {
+        Filter::BlackmanHarris { radius: radius }
+    }
+
+    pub fn radius(&self) -> f64 {
+        match *self {
+            Filter::Box { radius } => radius,
+            Filter::Triangle { radius } => radius,
+            Filter::Gaussian { radius, .. } => radius,
+            Filter::Mitchell { radius, .. } => radius,
+            Filter::Lanczos { radius, .. } => radius,
+            Filter::BlackmanHarris { radius } => radius
+        }
+    }
+
+    pub fn evaluate(&self, x: f64, y: f64) -> f64 {
+        let radius = self.radius();
+
+        if f64::abs(x) > radius || f64::abs(y) > radius {
+            return 0.0
+        }
+
+        self.evaluate_1d(x) * self.evaluate_1d(y)
+    }
+
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let (tag, parameters) = match *self {
+            Filter::Box { radius } => (0, [radius, 0.0, 0.0]),
+            Filter::Triangle { radius } => (1, [radius, 0.0, 0.0]),
+            Filter::Gaussian { radius, alpha } => (2, [radius, alpha, 0.0]),
+            Filter::Mitchell { radius, b, c } => (3, [radius, b, c]),
+            Filter::Lanczos { radius, tau } => (4, [radius, tau, 0.0]),
+            Filter::BlackmanHarris { radius } => (5, [radius, 0.0, 0.0])
+        };
+
+        write_u32(writer, tag)?;
+        for parameter in &parameters {
+            write_f64(writer, *parameter)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let tag = read_u32(reader)?;
+        let (a, b, c) = (read_f64(reader)?, read_f64(reader)?, read_f64(reader)?);
+
+        match tag {
+            0 => Ok(Filter::Box { radius: a }),
+            1 => Ok(Filter::Triangle { radius: a }),
+            2 => Ok(Filter::Gaussian { radius: a, alpha: b }),
+            3 => Ok(Filter::Mitchell { radius: a, b: b, c: c }),
+            4 => Ok(Filter::Lanczos { radius: a, tau: b }),
+            5 => Ok(Filter::BlackmanHarris { radius: a }),
+            _ => Err(invalid_data("unknown filter"))
+        }
+    }
+
+    fn evaluate_1d(&self, x: f64) -> f64 {
+        match *self {
+            Filter::Box { .. } => 1.0,
+            Filter::Triangle { radius } => f64::max(0.0, radius - f64::abs(x)),
+            Filter::Gaussian { radius, alpha } => {
+                f64::max(0.0, f64::exp(-alpha * x * x) - f64::exp(-alpha * radius * radius))
+            },
+            Filter::Mitchell { radius, b, c } => Filter::mitchell_1d(2.0 * x / radius, b, c),
+            Filter::Lanczos { tau, .. } => Filter::sinc(x) * Filter::sinc(x / tau),
+            Filter::BlackmanHarris { radius } => {
+                let t = (x + radius) / (2.0 * radius);
+                0.35875 - 0.48829 * f64::cos(2.0 * PI * t) + 0.14128 * f64::cos(4.0 * PI * t) - 0.01168 * f64::cos(6.0 * PI * t)
+            }
+        }
+    }
+
+    fn mitchell_1d(x: f64, b: f64, c: f64) -> f64 {
+        let x = f64::abs(x);
+
+        let value = if x < 1.0 {
+            (12.0 - 9.0 * b - 6.0 * c) * x.powi(3) + (-18.0 + 12.0 * b + 6.0 * c) * x.powi(2) + (6.0 - 2.0 * b)
+        } else if x < 2.0 {
+            (-b - 6.0 * c) * x.powi(3) + (6.0 * b + 30.0 * c) * x.powi(2) + (-12.0 * b - 48.0 * c) * x + (8.0 * b + 24.0 * c)
+        } else {
+            0.0
+        };
+
+        value / 6.0
+    }
+
+    fn sinc(x: f64) -> f64 {
+        if f64::abs(x) < 1e-5 {
+            return 1.0
+        }
+
+        let x = PI * x;
+        f64::sin(x) / x
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::box_filter(0.5)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::rendering::serialization::{write_u32, write_f64, read_u32, read_f64, invalid_data};
+
+use std::io::{self, Read, Write};
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum Integrator {
+    #[default]
+    PathTracing,
+    AmbientOcclusion { distance: f64 }
+}
+
+impl Integrator {
+    pub fn path_tracing() -> Self {
+        Integrator::PathTracing
+    }
+
+    pub fn ambient_occlusion(distance: f64) -> Self {
+        Integrator::AmbientOcclusion { distance: distance }
+    }
+
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let (tag, parameter) = match *self {
+            Integrator::PathTracing => (0, 0.0),
+            Integrator::AmbientOcclusion { distance } => (1, distance)
+        };
+
+        write_u32(writer, tag)?;
+        write_f64(writer, parameter)
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let tag = read_u32(reader)?;
+        let parameter = read_f64(reader)?;
+
+        match tag {
+            0 => Ok(Integrator::PathTracing),
+            1 => Ok(Integrator::AmbientOcclusion { distance: parameter }),
+            _ => Err(invalid_data("unknown integrator"))
+        }
+    }
+}
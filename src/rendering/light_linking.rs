@@ -0,0 +1,89 @@
+use crate::rendering::serialization::{write_u32, read_u32, invalid_data};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Read, Write};
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum LightLink {
+    Include(BTreeSet<u32>),
+    Exclude(BTreeSet<u32>)
+}
+
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct LightLinking {
+    links: BTreeMap<u32, LightLink>
+}
+
+impl LightLinking {
+    pub fn new() -> Self {
+        Self {
+            links: BTreeMap::new()
+        }
+    }
+
+    pub fn include(&mut self, light: u32, receivers: &[u32]) {
+        self.links.insert(light, LightLink::Include(receivers.iter().copied().collect()));
+    }
+
+    pub fn exclude(&mut self, light: u32, receivers: &[u32]) {
+        self.links.insert(light, LightLink::Exclude(receivers.iter().copied().collect()));
+    }
+
+    pub fn remove(&mut self, light: u32) {
+        self.links.remove(&light);
+    }
+
+    pub fn link(&self, light: u32) -> Option<&LightLink> {
+        self.links.get(&light)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+
+    pub fn illuminates(&self, light: Option<u32>, receiver: Option<u32>) -> bool {
+        match light.and_then(|light| self.links.get(&light)) {
+            Some(LightLink::Include(receivers)) => receiver.is_some_and(|receiver| receivers.contains(&receiver)),
+            Some(LightLink::Exclude(receivers)) => receiver.is_none_or(|receiver| !receivers.contains(&receiver)),
+            None => true
+        }
+    }
+
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_u32(writer, self.links.len() as u32)?;
+
+        for (&light, link) in &self.links {
+            let (tag, receivers) = match link {
+                LightLink::Include(receivers) => (0, receivers),
+                LightLink::Exclude(receivers) => (1, receivers)
+            };
+
+            write_u32(writer, light)?;
+            write_u32(writer, tag)?;
+            write_u32(writer, receivers.len() as u32)?;
+            for &receiver in receivers {
+                write_u32(writer, receiver)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut linking = Self::new();
+
+        for _ in 0..read_u32(reader)? {
+            let light = read_u32(reader)?;
+            let tag = read_u32(reader)?;
+            let receivers = (0..read_u32(reader)?).map(|_| read_u32(reader)).collect::<io::Result<Vec<u32>>>()?;
+
+            match tag {
+                0 => linking.include(light, &receivers),
+                1 => linking.exclude(light, &receivers),
+                _ => return Err(invalid_data("unknown light link"))
+            }
+        }
+
+        Ok(linking)
+    }
+}
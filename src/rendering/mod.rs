@@ -1,5 +1,30 @@
 pub mod render;
-pub use self::render::{RenderParams, render};
+pub use self::render::{RenderParams, RenderResult, render, render_with_handle};
 
 pub mod camera;
 pub use self::camera::Camera;
+
+pub mod filter;
+pub use self::filter::Filter;
+
+pub mod integrator;
+pub use self::integrator::Integrator;
+
+pub mod light_linking;
+pub use self::light_linking::{LightLinking, LightLink};
+
+pub mod film;
+pub use self::film::Film;
+
+pub mod render_handle;
+pub use self::render_handle::RenderHandle;
+
+pub mod checkpoint;
+pub use self::checkpoint::{Checkpoint, CheckpointParams};
+
+pub mod statistics;
+pub use self::statistics::RenderStatistics;
+
+pub mod distributed;
+
+pub(crate) mod serialization;
@@ -1,97 +1,259 @@
-use crate::structures::{Color, Ray, Image};
+use crate::structures::{Color, Ray, RayVisibility, Image, Vec3};
 use crate::hittables::Hittable;
-use crate::rendering::Camera;
+use crate::rendering::{Camera, Checkpoint, CheckpointParams, Film, Filter, Integrator, LightLinking, RenderHandle, RenderStatistics};
+use crate::rendering::statistics;
 use crate::skyboxes::Skybox;
+use crate::random::{self, thread_rng};
 
 use std::f64::INFINITY;
-use std::sync::Mutex;
+use std::ops::Range;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::mpsc::channel;
+use std::time::{Duration, Instant};
 
-use rand::prelude::thread_rng;
 use rand::distributions::{Distribution, Uniform};
 
 use scoped_threadpool::Pool;
 
+#[derive(Clone)]
 pub struct RenderParams {
     pub image_width: usize,
     pub image_height: usize,
     pub num_samples: u32,
     pub max_ray_depth: u32,
+    pub filter: Filter,
+    pub integrator: Integrator,
+    pub light_linking: LightLinking,
+    pub time_budget: Option<Duration>,
+    pub seed: u64,
+    pub checkpoint: Option<CheckpointParams>,
 }
 
-pub fn render<'a, T: FnMut(u32, u32) + 'a>(world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, camera: Arc<Camera>, params: &RenderParams, progress: T) -> Image {
+impl Default for RenderParams {
+    fn default() -> Self {
+        Self {
+            image_width: 800,
+            image_height: 600,
+            num_samples: 100,
+            max_ray_depth: 50,
+            filter: Filter::default(),
+            integrator: Integrator::default(),
+            light_linking: LightLinking::default(),
+            time_budget: None,
+            seed: 0,
+            checkpoint: None
+        }
+    }
+}
+
+pub struct RenderResult {
+    pub image: Image,
+    pub statistics: RenderStatistics
+}
+
+pub fn render<'a, T: FnMut(u32, u32) + 'a>(world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, camera: Arc<Camera>, params: &RenderParams, progress: T) -> RenderResult {
+    let handle = RenderHandle::new(params);
+    render_with_handle(world, skybox, camera, params, &handle, progress)
+}
+
+pub fn render_with_handle<'a, T: FnMut(u32, u32) + 'a>(world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, camera: Arc<Camera>, params: &RenderParams, handle: &RenderHandle, progress: T) -> RenderResult {
     let mut progress = progress;
+    let start = Instant::now();
+    let acceleration_memory = world.acceleration_memory();
+
+    if let Some(checkpoint) = &params.checkpoint {
+        if checkpoint.resume && Path::new(&checkpoint.path).exists() {
+            if let Some(saved) = load_checkpoint(params, checkpoint) {
+                handle.restore(saved);
+            }
+        }
+    }
+
+    let first_pass = handle.completed_passes();
+    let mut last_checkpoint = first_pass;
+
+    render_passes(world, skybox, camera, params, first_pass..params.num_samples, handle, |completed_passes| {
+        (&mut progress)(completed_passes, params.num_samples);
+
+        if let Some(checkpoint) = &params.checkpoint {
+            if checkpoint.interval > 0 && completed_passes >= last_checkpoint + checkpoint.interval {
+                last_checkpoint = completed_passes;
+                save_checkpoint(handle, params, checkpoint);
+            }
+        }
+    });
+
+    if let Some(checkpoint) = &params.checkpoint {
+        save_checkpoint(handle, params, checkpoint);
+    }
+
+    let mut statistics = handle.statistics();
+    statistics.elapsed = start.elapsed();
+    statistics.acceleration_memory = acceleration_memory;
+
+    RenderResult {
+        image: handle.current_image(),
+        statistics: statistics
+    }
+}
+
+pub(crate) fn render_passes<T: FnMut(u32)>(world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, camera: Arc<Camera>, params: &RenderParams, passes: Range<u32>, handle: &RenderHandle, on_pass: T) {
+    let mut on_pass = on_pass;
     let thread_count = get_thread_count();
     let mut pool = Pool::new(thread_count);
     let (tx, rx) = channel();
-    
-    let final_image = Arc::new(Mutex::new(Image::new(params.image_width, params.image_height)));
+
+    let deadline = params.time_budget.map(|budget| Instant::now() + budget);
+    let pass_count = passes.len();
     
     pool.scoped(|scoped| {
-        for _ in 0..params.num_samples {
+        for pass in passes {
             let tx = tx.clone();
             let world = world.clone();
             let skybox = skybox.clone();
             let camera = camera.clone();
-            let final_image = final_image.clone();
+            let handle = handle.clone();
             
             scoped.execute(move || {
-                let sample = render_sample(world, skybox, camera, params);
-                let mut render = final_image.lock().unwrap();
-                *render += sample / params.num_samples as f64;
-                tx.send(()).unwrap();
+                let should_stop = || handle.is_cancelled() || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+                let start = Instant::now();
+
+                let sample = match should_stop() {
+                    true => None,
+                    false => {
+                        random::reseed(random::mix_seed(params.seed, pass as u64));
+                        render_sample(world, skybox, camera, params, &should_stop)
+                    }
+                };
+
+                statistics::record_thread_time(start.elapsed());
+                handle.record_statistics(&statistics::take_thread_statistics());
+
+                let rendered = match sample {
+                    Some(sample) => {
+                        handle.accumulate(pass, 1, sample);
+                        true
+                    },
+                    None => false
+                };
+
+                tx.send(rendered).unwrap();
             });
         }
 
-        for i in 0..params.num_samples {
-            rx.recv().unwrap();
-            (&mut progress)(i + 1, params.num_samples);
+        for _ in 0..pass_count {
+            if rx.recv().unwrap() {
+                on_pass(handle.completed_passes());
+            }
         }
     });
+}
 
-    let final_image = (*final_image.lock().unwrap()).clone();
-    final_image
+fn load_checkpoint(params: &RenderParams, checkpoint: &CheckpointParams) -> Option<Checkpoint> {
+    match Checkpoint::read(&checkpoint.path) {
+        Ok(saved) if saved.is_compatible(params) => Some(saved),
+        Ok(_) => {
+            eprintln!("Checkpoint {} does not match the render params, starting a fresh render.", checkpoint.path);
+            None
+        },
+        Err(error) => {
+            eprintln!("Failed to read checkpoint {}, starting a fresh render: {}", checkpoint.path, error);
+            None
+        }
+    }
 }
 
-fn render_sample(world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, camera: Arc<Camera>, params: &RenderParams) -> Image {
+fn save_checkpoint(handle: &RenderHandle, params: &RenderParams, checkpoint: &CheckpointParams) {
+    if let Err(error) = handle.checkpoint(params).write(&checkpoint.path) {
+        eprintln!("Failed to write checkpoint {}: {}", checkpoint.path, error);
+    }
+}
+
+fn render_sample<F: Fn() -> bool>(world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, camera: Arc<Camera>, params: &RenderParams, should_stop: &F) -> Option<Film> {
     let mut rng = thread_rng();
     let uniform_distribution = Uniform::from(-0.5..=0.5);
-    let mut image = Image::new(params.image_width, params.image_height);
+    let mut film = Film::new(params.image_width, params.image_height);
 
     for x in 0..params.image_width {
+        if should_stop() {
+            return None
+        }
+
         for y in 0..params.image_height {
             let fx = (x as f64 + 0.5) + uniform_distribution.sample(&mut rng);
             let fy = (y as f64 + 0.5) + uniform_distribution.sample(&mut rng);
 
-            let u = fx as f64 / params.image_width as f64;
-            let v = 1.0 - (fy as f64 / params.image_height as f64);
+            let u = fx / params.image_width as f64;
+            let v = 1.0 - (fy / params.image_height as f64);
 
             let ray = camera.get_ray(u, v);
-            let color = ray_color(&ray, world.clone(), skybox.clone(), params.max_ray_depth);
-
-            image[(x, y)] = color;
+            let color = match params.integrator {
+                Integrator::PathTracing => ray_color(&ray, world.clone(), skybox.clone(), &params.light_linking, None, params.max_ray_depth, params.max_ray_depth),
+                Integrator::AmbientOcclusion { distance } => ambient_occlusion(&ray, world.as_ref(), skybox.as_ref(), distance)
+            };
 
+            film.add_sample(&params.filter, fx, fy, color);
         }
     }
 
-    image
+    Some(film)
 }
 
-fn ray_color(ray: &Ray, world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, depth: u32) -> Color {
+fn ray_color(ray: &Ray, world: Arc<dyn Hittable>, skybox: Arc<dyn Skybox>, light_linking: &LightLinking, receiver: Option<u32>, depth: u32, max_depth: u32) -> Color {
     if depth <= 0 {
+        statistics::record_path(max_depth);
         return Color::new(0.0, 0.0, 0.0);
     }
+
+    match depth == max_depth {
+        true => statistics::record_primary_ray(),
+        false => statistics::record_secondary_ray()
+    }
     
     match world.hit(&ray, 0.001, INFINITY) {
         Some(hit) => {
-            let emitted = hit.material.emitted(hit.u, hit.v, hit.point);
+            let emitted = match ray.kind == RayVisibility::CAMERA || light_linking.illuminates(hit.object_id, receiver) {
+                true => hit.material.emitted(hit.u, hit.v, hit.point),
+                false => Color::new(0.0, 0.0, 0.0)
+            };
+
             match hit.material.scatter(ray, &hit) {
-                Some((scattered_ray, attenuation)) => emitted + (attenuation * ray_color(&scattered_ray, world, skybox, depth - 1)),
-                None => emitted
+                Some((scattered_ray, attenuation)) => emitted + (attenuation * ray_color(&scattered_ray, world, skybox, light_linking, hit.object_id, depth - 1, max_depth)),
+                None => {
+                    statistics::record_path(max_depth - depth + 1);
+                    emitted
+                }
             }
         }
-        None => skybox.get_color(&ray)
+        None => {
+            statistics::record_path(max_depth - depth + 1);
+            skybox.get_color(&ray)
+        }
+    }
+}
+
+fn ambient_occlusion(ray: &Ray, world: &dyn Hittable, skybox: &dyn Skybox, distance: f64) -> Color {
+    statistics::record_primary_ray();
+    statistics::record_path(1);
+
+    match world.hit(ray, 0.001, f64::INFINITY) {
+        Some(hit) => {
+            let normal = hit.get_facing_normal(ray);
+            let direction = match normal + Vec3::random_in_unit_sphere().normalized() {
+                direction if direction.squared_length() > 1e-12 => direction.normalized(),
+                _ => normal
+            };
+
+            statistics::record_shadow_ray();
+
+            match world.occluded(&Ray::with_time(hit.point, direction, ray.time).with_kind(RayVisibility::SHADOW), 0.001, distance) {
+                true => Color::new(0.0, 0.0, 0.0),
+                false => Color::new(1.0, 1.0, 1.0)
+            }
+        },
+        None => skybox.get_color(ray)
     }
 }
 
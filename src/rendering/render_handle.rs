@@ -0,0 +1,112 @@
+use crate::structures::Image;
+use crate::rendering::{Checkpoint, Film, RenderParams, RenderStatistics};
+
+use std::collections::BTreeMap;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+struct Accumulator {
+    film: Film,
+    completed_passes: u32,
+    pending: BTreeMap<u32, (u32, Film)>
+}
+
+#[derive(Clone)]
+pub struct RenderHandle {
+    cancelled: Arc<AtomicBool>,
+    accumulator: Arc<Mutex<Accumulator>>,
+    statistics: Arc<Mutex<RenderStatistics>>
+}
+
+impl RenderHandle {
+    pub fn new(params: &RenderParams) -> Self {
+        let accumulator = Accumulator {
+            film: Film::new(params.image_width, params.image_height),
+            completed_passes: 0,
+            pending: BTreeMap::new()
+        };
+
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            accumulator: Arc::new(Mutex::new(accumulator)),
+            statistics: Arc::new(Mutex::new(RenderStatistics::default()))
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn completed_passes(&self) -> u32 {
+        self.accumulator.lock().unwrap().completed_passes
+    }
+
+    pub fn current_image(&self) -> Image {
+        self.accumulator.lock().unwrap().film.to_image()
+    }
+
+    pub fn current_film(&self) -> Film {
+        self.accumulator.lock().unwrap().film.clone()
+    }
+
+    pub fn statistics(&self) -> RenderStatistics {
+        self.statistics.lock().unwrap().clone()
+    }
+
+    pub fn checkpoint(&self, params: &RenderParams) -> Checkpoint {
+        let accumulator = self.accumulator.lock().unwrap();
+
+        Checkpoint {
+            seed: params.seed,
+            max_ray_depth: params.max_ray_depth,
+            filter: params.filter,
+            integrator: params.integrator,
+            light_linking: params.light_linking.clone(),
+            completed_passes: accumulator.completed_passes,
+            film: accumulator.film.clone()
+        }
+    }
+
+    pub fn restore(&self, checkpoint: Checkpoint) {
+        let mut accumulator = self.accumulator.lock().unwrap();
+        accumulator.film = checkpoint.film;
+        accumulator.completed_passes = checkpoint.completed_passes;
+        accumulator.pending.clear();
+    }
+
+    pub(crate) fn record_statistics(&self, statistics: &RenderStatistics) {
+        self.statistics.lock().unwrap().merge(statistics);
+    }
+
+    pub(crate) fn take_statistics(&self) -> RenderStatistics {
+        mem::take(&mut *self.statistics.lock().unwrap())
+    }
+
+    pub(crate) fn reset(&self, first_pass: u32) {
+        let mut accumulator = self.accumulator.lock().unwrap();
+        accumulator.film = Film::new(accumulator.film.width, accumulator.film.height);
+        accumulator.completed_passes = first_pass;
+        accumulator.pending.clear();
+    }
+
+    pub(crate) fn accumulate(&self, first_pass: u32, pass_count: u32, film: Film) {
+        let mut accumulator = self.accumulator.lock().unwrap();
+        accumulator.pending.insert(first_pass, (pass_count, film));
+
+        loop {
+            let next = accumulator.completed_passes;
+            match accumulator.pending.remove(&next) {
+                Some((pass_count, film)) => {
+                    accumulator.film.merge(&film);
+                    accumulator.completed_passes += pass_count;
+                },
+                None => break
+            }
+        }
+    }
+}
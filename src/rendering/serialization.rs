@@ -0,0 +1,47 @@
+use crate::structures::Color;
+
+use std::io::{self, Read, Write};
+
+pub(crate) fn write_u32<W: Write>(writer: &mut W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn write_u64<W: Write>(writer: &mut W, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn write_f64<W: Write>(writer: &mut W, value: f64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn write_color<W: Write>(writer: &mut W, color: Color) -> io::Result<()> {
+    write_f64(writer, color.x)?;
+    write_f64(writer, color.y)?;
+    write_f64(writer, color.z)
+}
+
+pub(crate) fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub(crate) fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub(crate) fn read_f64<R: Read>(reader: &mut R) -> io::Result<f64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+pub(crate) fn read_color<R: Read>(reader: &mut R) -> io::Result<Color> {
+    Ok(Color::new(read_f64(reader)?, read_f64(reader)?, read_f64(reader)?))
+}
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -0,0 +1,219 @@
+use crate::rendering::serialization::{write_u64, write_f64, read_u64, read_f64, invalid_data};
+
+use std::cell::RefCell;
+use std::fmt::Write as FmtWrite;
+use std::io::{self, Read, Write};
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+const MAX_SERIALIZED_ENTRIES: u64 = 1 << 16;
+
+thread_local! {
+    static THREAD_STATISTICS: RefCell<RenderStatistics> = RefCell::new(RenderStatistics::default());
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RenderStatistics {
+    pub primary_rays: u64,
+    pub secondary_rays: u64,
+    pub shadow_rays: u64,
+    pub bvh_node_tests: u64,
+    pub primitive_tests: u64,
+    pub paths: u64,
+    pub total_path_length: u64,
+    pub depth_histogram: Vec<u64>,
+    pub elapsed: Duration,
+    pub thread_times: Vec<Duration>,
+    pub acceleration_memory: usize,
+    thread_ids: Vec<Option<ThreadId>>
+}
+
+impl RenderStatistics {
+    pub fn total_rays(&self) -> u64 {
+        self.primary_rays + self.secondary_rays + self.shadow_rays
+    }
+
+    pub fn rays_per_second(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+
+        match seconds > 0.0 {
+            true => self.total_rays() as f64 / seconds,
+            false => 0.0
+        }
+    }
+
+    pub fn average_path_length(&self) -> f64 {
+        match self.paths > 0 {
+            true => self.total_path_length as f64 / self.paths as f64,
+            false => 0.0
+        }
+    }
+
+    pub fn merge(&mut self, other: &RenderStatistics) {
+        self.primary_rays += other.primary_rays;
+        self.secondary_rays += other.secondary_rays;
+        self.shadow_rays += other.shadow_rays;
+        self.bvh_node_tests += other.bvh_node_tests;
+        self.primitive_tests += other.primitive_tests;
+        self.paths += other.paths;
+        self.total_path_length += other.total_path_length;
+
+        if self.depth_histogram.len() < other.depth_histogram.len() {
+            self.depth_histogram.resize(other.depth_histogram.len(), 0);
+        }
+
+        for (i, count) in other.depth_histogram.iter().enumerate() {
+            self.depth_histogram[i] += count;
+        }
+
+        for (i, id) in other.thread_ids.iter().enumerate() {
+            match id {
+                Some(id) => self.add_thread_time(*id, other.thread_times[i]),
+                None => {
+                    self.thread_ids.push(None);
+                    self.thread_times.push(other.thread_times[i]);
+                }
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+
+        json.push('{');
+        write!(json, "\"primary_rays\":{},", self.primary_rays).unwrap();
+        write!(json, "\"secondary_rays\":{},", self.secondary_rays).unwrap();
+        write!(json, "\"shadow_rays\":{},", self.shadow_rays).unwrap();
+        write!(json, "\"total_rays\":{},", self.total_rays()).unwrap();
+        write!(json, "\"rays_per_second\":{},", self.rays_per_second()).unwrap();
+        write!(json, "\"bvh_node_tests\":{},", self.bvh_node_tests).unwrap();
+        write!(json, "\"primitive_tests\":{},", self.primitive_tests).unwrap();
+        write!(json, "\"paths\":{},", self.paths).unwrap();
+        write!(json, "\"average_path_length\":{},", self.average_path_length()).unwrap();
+        write!(json, "\"depth_histogram\":{},", json_array(self.depth_histogram.iter())).unwrap();
+        write!(json, "\"elapsed_seconds\":{},", self.elapsed.as_secs_f64()).unwrap();
+        write!(json, "\"thread_seconds\":{},", json_array(self.thread_times.iter().map(|time| time.as_secs_f64()))).unwrap();
+        write!(json, "\"acceleration_memory_bytes\":{}", self.acceleration_memory).unwrap();
+        json.push('}');
+
+        json
+    }
+
+    pub(crate) fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for value in [self.primary_rays, self.secondary_rays, self.shadow_rays, self.bvh_node_tests, self.primitive_tests, self.paths, self.total_path_length] {
+            write_u64(writer, value)?;
+        }
+
+        write_u64(writer, self.depth_histogram.len() as u64)?;
+        for &count in &self.depth_histogram {
+            write_u64(writer, count)?;
+        }
+
+        write_u64(writer, self.thread_times.len() as u64)?;
+        for time in &self.thread_times {
+            write_f64(writer, time.as_secs_f64())?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut statistics = Self {
+            primary_rays: read_u64(reader)?,
+            secondary_rays: read_u64(reader)?,
+            shadow_rays: read_u64(reader)?,
+            bvh_node_tests: read_u64(reader)?,
+            primitive_tests: read_u64(reader)?,
+            paths: read_u64(reader)?,
+            total_path_length: read_u64(reader)?,
+            ..Default::default()
+        };
+
+        let histogram_length = read_u64(reader)?;
+        if histogram_length > MAX_SERIALIZED_ENTRIES {
+            return Err(invalid_data("depth histogram too large"))
+        }
+
+        for _ in 0..histogram_length {
+            statistics.depth_histogram.push(read_u64(reader)?);
+        }
+
+        let thread_count = read_u64(reader)?;
+        if thread_count > MAX_SERIALIZED_ENTRIES {
+            return Err(invalid_data("too many thread times"))
+        }
+
+        for _ in 0..thread_count {
+            let seconds = read_f64(reader)?;
+            if !(seconds.is_finite() && seconds >= 0.0) {
+                return Err(invalid_data("invalid thread time"))
+            }
+
+            statistics.thread_ids.push(None);
+            statistics.thread_times.push(Duration::from_secs_f64(seconds));
+        }
+
+        Ok(statistics)
+    }
+
+    pub(crate) fn add_thread_time(&mut self, id: ThreadId, time: Duration) {
+        match self.thread_ids.iter().position(|&el| el == Some(id)) {
+            Some(i) => self.thread_times[i] += time,
+            None => {
+                self.thread_ids.push(Some(id));
+                self.thread_times.push(time);
+            }
+        }
+    }
+}
+
+fn json_array<T: ToString, I: Iterator<Item = T>>(values: I) -> String {
+    format!("[{}]", values.map(|value| value.to_string()).collect::<Vec<String>>().join(","))
+}
+
+fn with_thread_statistics<F: FnOnce(&mut RenderStatistics)>(f: F) {
+    THREAD_STATISTICS.with(|statistics| f(&mut statistics.borrow_mut()));
+}
+
+pub(crate) fn take_thread_statistics() -> RenderStatistics {
+    THREAD_STATISTICS.with(|statistics| statistics.replace(RenderStatistics::default()))
+}
+
+pub(crate) fn record_primary_ray() {
+    with_thread_statistics(|statistics| statistics.primary_rays += 1);
+}
+
+pub(crate) fn record_secondary_ray() {
+    with_thread_statistics(|statistics| statistics.secondary_rays += 1);
+}
+
+pub(crate) fn record_shadow_ray() {
+    with_thread_statistics(|statistics| statistics.shadow_rays += 1);
+}
+
+pub(crate) fn record_bvh_node_test() {
+    with_thread_statistics(|statistics| statistics.bvh_node_tests += 1);
+}
+
+pub(crate) fn record_primitive_test() {
+    with_thread_statistics(|statistics| statistics.primitive_tests += 1);
+}
+
+pub(crate) fn record_path(length: u32) {
+    with_thread_statistics(|statistics| {
+        let length = length as usize;
+
+        if statistics.depth_histogram.len() <= length {
+            statistics.depth_histogram.resize(length + 1, 0);
+        }
+
+        statistics.paths += 1;
+        statistics.total_path_length += length as u64;
+        statistics.depth_histogram[length] += 1;
+    });
+}
+
+pub(crate) fn record_thread_time(time: Duration) {
+    let id = thread::current().id();
+    with_thread_statistics(|statistics| statistics.add_thread_time(id, time));
+}
@@ -0,0 +1,2 @@
+pub mod scene_graph;
+pub use self::scene_graph::{SceneGraph, SceneNode, NodeId};
@@ -0,0 +1,207 @@
+use crate::structures::{Matrix4, Transform};
+use crate::hittables::{Hittable, HittableList, MatrixInstance, FlatBVH, BVHBuilder};
+
+use std::sync::Arc;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct NodeId(usize);
+
+pub struct SceneNode {
+    pub name: String,
+    pub transform: Transform,
+    pub visible: bool,
+    pub hittable: Option<Arc<dyn Hittable>>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>
+}
+
+impl SceneNode {
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+}
+
+pub struct SceneGraph {
+    nodes: Vec<Option<SceneNode>>,
+    root: NodeId
+}
+
+impl SceneGraph {
+    pub fn new() -> Self {
+        let root = SceneNode {
+            name: "root".to_string(),
+            transform: Transform::identity(),
+            visible: true,
+            hittable: None,
+            parent: None,
+            children: vec![]
+        };
+
+        Self {
+            nodes: vec![Some(root)],
+            root: NodeId(0)
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    pub fn add_node(&mut self, parent: NodeId, name: &str, transform: Transform) -> NodeId {
+        self.insert(parent, name, transform, None)
+    }
+
+    pub fn add_object(&mut self, parent: NodeId, name: &str, hittable: Arc<dyn Hittable>, transform: Transform) -> NodeId {
+        self.insert(parent, name, transform, Some(hittable))
+    }
+
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.nodes.get(id.0).is_some_and(|node| node.is_some())
+    }
+
+    pub fn node(&self, id: NodeId) -> &SceneNode {
+        match self.nodes.get(id.0) {
+            Some(Some(node)) => node,
+            _ => panic!("Node {:?} is not part of the scene graph.", id)
+        }
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> &mut SceneNode {
+        match self.nodes.get_mut(id.0) {
+            Some(Some(node)) => node,
+            _ => panic!("Node {:?} is not part of the scene graph.", id)
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<NodeId> {
+        self.nodes.iter().position(|node| node.as_ref().is_some_and(|node| node.name == name)).map(NodeId)
+    }
+
+    pub fn find_path(&self, path: &str) -> Option<NodeId> {
+        let mut current = self.root;
+
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            current = *self.node(current).children.iter().find(|&&child| self.node(child).name == name)?;
+        }
+
+        Some(current)
+    }
+
+    pub fn set_parent(&mut self, id: NodeId, parent: NodeId) {
+        assert!(id != self.root, "The scene graph root cannot be reparented.");
+        assert!(!self.is_ancestor(id, parent), "Reparenting {:?} under {:?} would create a cycle.", id, parent);
+
+        self.detach(id);
+        self.node_mut(parent).children.push(id);
+        self.node_mut(id).parent = Some(parent);
+    }
+
+    pub fn remove(&mut self, id: NodeId) {
+        assert!(id != self.root, "The scene graph root cannot be removed.");
+
+        self.detach(id);
+
+        let mut pending = vec![id];
+        while let Some(id) = pending.pop() {
+            if let Some(node) = self.nodes[id.0].take() {
+                pending.extend(node.children);
+            }
+        }
+    }
+
+    pub fn world_matrix(&self, id: NodeId) -> Matrix4 {
+        let node = self.node(id);
+
+        match node.parent {
+            Some(parent) => self.world_matrix(parent) * node.transform.to_matrix(),
+            None => node.transform.to_matrix()
+        }
+    }
+
+    pub fn is_visible(&self, id: NodeId) -> bool {
+        let node = self.node(id);
+        node.visible && node.parent.is_none_or(|parent| self.is_visible(parent))
+    }
+
+    pub fn flatten(&self) -> HittableList {
+        let mut list = HittableList::new();
+        self.flatten_node(self.root, Matrix4::identity(), &mut list);
+        list
+    }
+
+    pub fn build(&self, builder: &BVHBuilder) -> Option<FlatBVH> {
+        let list = self.flatten();
+
+        match list.hittables.is_empty() {
+            true => None,
+            false => Some(FlatBVH::with_builder(&list, builder))
+        }
+    }
+
+    fn flatten_node(&self, id: NodeId, parent_matrix: Matrix4, list: &mut HittableList) {
+        let node = self.node(id);
+
+        let matrix = parent_matrix * node.transform.to_matrix();
+
+        if !node.visible || matrix.inverse().is_none() {
+            return
+        }
+
+        if let Some(hittable) = &node.hittable {
+            match matrix == Matrix4::identity() {
+                true => list.add(hittable.clone()),
+                false => list.add(Arc::new(MatrixInstance::new(hittable.clone(), matrix)))
+            }
+        }
+
+        for &child in &node.children {
+            self.flatten_node(child, matrix, list);
+        }
+    }
+
+    fn insert(&mut self, parent: NodeId, name: &str, transform: Transform, hittable: Option<Arc<dyn Hittable>>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+
+        self.node_mut(parent).children.push(id);
+        self.nodes.push(Some(SceneNode {
+            name: name.to_string(),
+            transform: transform,
+            visible: true,
+            hittable: hittable,
+            parent: Some(parent),
+            children: vec![]
+        }));
+
+        id
+    }
+
+    fn detach(&mut self, id: NodeId) {
+        if let Some(parent) = self.node(id).parent {
+            self.node_mut(parent).children.retain(|&child| child != id);
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: NodeId, id: NodeId) -> bool {
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            if id == ancestor {
+                return true
+            }
+
+            current = self.node(id).parent;
+        }
+
+        false
+    }
+}
+
+impl Default for SceneGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
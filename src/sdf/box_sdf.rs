@@ -0,0 +1,38 @@
+use crate::structures::{Vec3, Point3};
+use crate::sdf::SDF;
+
+pub struct BoxSDF {
+    pub center: Point3,
+    pub half_extent: Vec3,
+    pub rounding: f64
+}
+
+impl BoxSDF {
+    pub fn new(center: Point3, half_extent: Vec3) -> Self {
+        Self::rounded(center, half_extent, 0.0)
+    }
+
+    pub fn rounded(center: Point3, half_extent: Vec3, rounding: f64) -> Self {
+        Self {
+            center: center,
+            half_extent: half_extent,
+            rounding: rounding
+        }
+    }
+}
+
+impl SDF for BoxSDF {
+    fn distance(&self, point: Point3) -> f64 {
+        let p = point - self.center;
+        let q = Vec3::new(
+            p.x.abs() - self.half_extent.x + self.rounding,
+            p.y.abs() - self.half_extent.y + self.rounding,
+            p.z.abs() - self.half_extent.z + self.rounding
+        );
+
+        let outside = Vec3::new(q.x.max(0.0), q.y.max(0.0), q.z.max(0.0)).length();
+        let inside = f64::min(q.x.max(q.y).max(q.z), 0.0);
+
+        outside + inside - self.rounding
+    }
+}
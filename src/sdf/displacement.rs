@@ -0,0 +1,24 @@
+use crate::structures::Point3;
+use crate::sdf::SDF;
+
+use std::sync::Arc;
+
+pub struct Displacement {
+    pub sdf: Arc<dyn SDF>,
+    pub displacement: Arc<dyn Fn(Point3) -> f64 + Send + Sync>
+}
+
+impl Displacement {
+    pub fn new(sdf: Arc<dyn SDF>, displacement: Arc<dyn Fn(Point3) -> f64 + Send + Sync>) -> Self {
+        Self {
+            sdf: sdf,
+            displacement: displacement
+        }
+    }
+}
+
+impl SDF for Displacement {
+    fn distance(&self, point: Point3) -> f64 {
+        self.sdf.distance(point) + (self.displacement)(point)
+    }
+}
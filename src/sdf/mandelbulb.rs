@@ -0,0 +1,49 @@
+use crate::structures::{Vec3, Point3};
+use crate::sdf::SDF;
+
+pub struct Mandelbulb {
+    pub power: f64,
+    pub iterations: u32
+}
+
+impl Mandelbulb {
+    pub fn new(power: f64, iterations: u32) -> Self {
+        Self {
+            power: power,
+            iterations: iterations
+        }
+    }
+}
+
+impl Default for Mandelbulb {
+    fn default() -> Self {
+        Self::new(8.0, 12)
+    }
+}
+
+impl SDF for Mandelbulb {
+    fn distance(&self, point: Point3) -> f64 {
+        let mut z = point;
+        let mut derivative = 1.0;
+        let mut r = z.length();
+
+        for _ in 0..self.iterations {
+            if r > 2.0 {
+                break
+            }
+
+            let theta = f64::acos(f64::clamp(z.z / r, -1.0, 1.0)) * self.power;
+            let phi = f64::atan2(z.y, z.x) * self.power;
+
+            derivative = r.powf(self.power - 1.0) * self.power * derivative + 1.0;
+            z = r.powf(self.power) * Vec3::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()) + point;
+            r = z.length();
+
+            if r == 0.0 {
+                return 0.0
+            }
+        }
+
+        0.5 * r.ln() * r / derivative
+    }
+}
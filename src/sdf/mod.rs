@@ -0,0 +1,26 @@
+pub mod traits;
+pub use self::traits::SDF;
+
+pub mod sphere_sdf;
+pub use self::sphere_sdf::SphereSDF;
+
+pub mod box_sdf;
+pub use self::box_sdf::BoxSDF;
+
+pub mod torus_sdf;
+pub use self::torus_sdf::TorusSDF;
+
+pub mod mandelbulb;
+pub use self::mandelbulb::Mandelbulb;
+
+pub mod smooth_union;
+pub use self::smooth_union::SmoothUnion;
+
+pub mod twist;
+pub use self::twist::Twist;
+
+pub mod repetition;
+pub use self::repetition::Repetition;
+
+pub mod displacement;
+pub use self::displacement::Displacement;
@@ -0,0 +1,32 @@
+use crate::structures::{Vec3, Point3};
+use crate::sdf::SDF;
+
+use std::sync::Arc;
+
+pub struct Repetition {
+    pub sdf: Arc<dyn SDF>,
+    pub period: Vec3
+}
+
+impl Repetition {
+    pub fn new(sdf: Arc<dyn SDF>, period: Vec3) -> Self {
+        Self {
+            sdf: sdf,
+            period: period
+        }
+    }
+}
+
+impl SDF for Repetition {
+    fn distance(&self, point: Point3) -> f64 {
+        let mut p = point;
+
+        for i in 0..3 {
+            if self.period[i] > 0.0 {
+                p[i] -= self.period[i] * (p[i] / self.period[i]).round();
+            }
+        }
+
+        self.sdf.distance(p)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::structures::Point3;
+use crate::sdf::SDF;
+
+use std::sync::Arc;
+
+pub struct SmoothUnion {
+    pub a: Arc<dyn SDF>,
+    pub b: Arc<dyn SDF>,
+    pub smoothness: f64
+}
+
+impl SmoothUnion {
+    pub fn new(a: Arc<dyn SDF>, b: Arc<dyn SDF>, smoothness: f64) -> Self {
+        Self {
+            a: a,
+            b: b,
+            smoothness: smoothness
+        }
+    }
+}
+
+impl SDF for SmoothUnion {
+    fn distance(&self, point: Point3) -> f64 {
+        let a = self.a.distance(point);
+        let b = self.b.distance(point);
+
+        if self.smoothness <= 0.0 {
+            return f64::min(a, b)
+        }
+
+        let h = f64::clamp(0.5 + 0.5 * (b - a) / self.smoothness, 0.0, 1.0);
+        b + (a - b) * h - self.smoothness * h * (1.0 - h)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::structures::Point3;
+use crate::sdf::SDF;
+
+pub struct SphereSDF {
+    pub center: Point3,
+    pub radius: f64
+}
+
+impl SphereSDF {
+    pub fn new(center: Point3, radius: f64) -> Self {
+        Self {
+            center: center,
+            radius: radius
+        }
+    }
+}
+
+impl SDF for SphereSDF {
+    fn distance(&self, point: Point3) -> f64 {
+        (point - self.center).length() - self.radius
+    }
+}
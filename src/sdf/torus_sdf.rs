@@ -0,0 +1,27 @@
+use crate::structures::Point3;
+use crate::sdf::SDF;
+
+pub struct TorusSDF {
+    pub center: Point3,
+    pub major_radius: f64,
+    pub minor_radius: f64
+}
+
+impl TorusSDF {
+    pub fn new(center: Point3, major_radius: f64, minor_radius: f64) -> Self {
+        Self {
+            center: center,
+            major_radius: major_radius,
+            minor_radius: minor_radius
+        }
+    }
+}
+
+impl SDF for TorusSDF {
+    fn distance(&self, point: Point3) -> f64 {
+        let p = point - self.center;
+        let ring = f64::sqrt(p.x * p.x + p.z * p.z) - self.major_radius;
+
+        f64::sqrt(ring * ring + p.y * p.y) - self.minor_radius
+    }
+}
@@ -0,0 +1,11 @@
+use crate::structures::Point3;
+
+pub trait SDF: Send + Sync {
+    fn distance(&self, point: Point3) -> f64;
+}
+
+impl<F: Fn(Point3) -> f64 + Send + Sync> SDF for F {
+    fn distance(&self, point: Point3) -> f64 {
+        self(point)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::structures::Point3;
+use crate::sdf::SDF;
+
+use std::sync::Arc;
+
+pub struct Twist {
+    pub sdf: Arc<dyn SDF>,
+    pub amount: f64
+}
+
+impl Twist {
+    pub fn new(sdf: Arc<dyn SDF>, amount: f64) -> Self {
+        Self {
+            sdf: sdf,
+            amount: amount
+        }
+    }
+}
+
+impl SDF for Twist {
+    fn distance(&self, point: Point3) -> f64 {
+        let angle = self.amount * point.y;
+        let (sin, cos) = angle.sin_cos();
+
+        self.sdf.distance(Point3::new(cos * point.x - sin * point.z, point.y, sin * point.x + cos * point.z))
+    }
+}
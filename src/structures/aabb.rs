@@ -1,4 +1,4 @@
-use crate::structures::{Point3, Ray};
+use crate::structures::{Vec3, Point3, Ray};
 
 use std::ops::Add;
 
@@ -36,12 +36,42 @@ impl AABB {
     }
 
     pub fn encapsulate(&mut self, other: AABB) {
-        let mut points_0 = self.get_points().iter().copied().collect();
-        let mut points_1 = other.get_points().iter().copied().collect();
-        let mut points = vec![];
-        points.append(&mut points_0);
-        points.append(&mut points_1);
-        *self = AABB::from_points(&points)
+        for i in 0..3 {
+            self.min[i] = f64::min(self.min[i], other.min[i]);
+            self.max[i] = f64::max(self.max[i], other.max[i]);
+        }
+    }
+
+    pub fn encapsulate_point(&mut self, point: Point3) {
+        for i in 0..3 {
+            self.min[i] = f64::min(self.min[i], point[i]);
+            self.max[i] = f64::max(self.max[i], point[i]);
+        }
+    }
+
+    pub fn centroid(&self) -> Point3 {
+        0.5 * (self.min + self.max)
+    }
+
+    pub fn extent(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    pub fn surface_area(&self) -> f64 {
+        let extent = self.extent();
+        2.0 * (extent.x * extent.y + extent.y * extent.z + extent.z * extent.x)
+    }
+
+    pub fn largest_axis(&self) -> usize {
+        let extent = self.extent();
+
+        if extent.x > extent.y && extent.x > extent.z {
+            0
+        } else if extent.y > extent.z {
+            1
+        } else {
+            2
+        }
     }
 
     pub fn hit(&self, ray: Ray, t_min: f64, t_max: f64) -> bool {
@@ -66,6 +96,44 @@ impl AABB {
         true
     }
 
+    pub fn hit_with_inverse(&self, origin: Point3, inverse_direction: Vec3, t_min: f64, t_max: f64) -> bool {
+        let mut t_min = t_min;
+        let mut t_max = t_max;
+
+        for i in 0..3 {
+            let v_min = (self.min[i] - origin[i]) * inverse_direction[i];
+            let v_max = (self.max[i] - origin[i]) * inverse_direction[i];
+
+            t_min = f64::max(f64::min(v_min, v_max), t_min);
+            t_max = f64::min(f64::max(v_min, v_max), t_max);
+
+            if t_max < t_min {
+                return false
+            }
+        }
+
+        true
+    }
+
+    pub fn hit_interval(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, f64)> {
+        let mut t_min = t_min;
+        let mut t_max = t_max;
+
+        for i in 0..3 {
+            let v_min = (self.min[i] - ray.origin[i]) / ray.direction[i];
+            let v_max = (self.max[i] - ray.origin[i]) / ray.direction[i];
+
+            t_min = f64::max(f64::min(v_min, v_max), t_min);
+            t_max = f64::min(f64::max(v_min, v_max), t_max);
+
+            if t_max < t_min {
+                return None
+            }
+        }
+
+        Some((t_min, t_max))
+    }
+
     pub fn get_points(&self) -> [Point3; 8] {
         [
             Point3::new(self.min.x, self.min.y, self.min.z),
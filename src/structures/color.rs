@@ -1,6 +1,7 @@
 use crate::structures::Vec3;
+use crate::random::thread_rng;
 
-use rand::prelude::{thread_rng, Rng};
+use rand::prelude::Rng;
 
 pub type Color = Vec3;
 
@@ -1,4 +1,4 @@
-use crate::structures::{Vec3, Point3, Ray};
+use crate::structures::{Vec3, Point3, Ray, Color};
 use crate::materials::Material;
 
 use std::sync::Arc;
@@ -10,7 +10,10 @@ pub struct HitRecord {
     pub material: Arc<dyn Material>,
     pub t: f64,
     pub u: f64,
-    pub v: f64
+    pub v: f64,
+    pub tangent: Option<Vec3>,
+    pub color: Option<Color>,
+    pub object_id: Option<u32>
 }
 
 impl HitRecord {
@@ -21,7 +24,24 @@ impl HitRecord {
             material: material,
             t: t,
             u: u,
-            v: v
+            v: v,
+            tangent: None,
+            color: None,
+            object_id: None
+        }
+    }
+
+    pub fn with_tangent(self, tangent: Vec3) -> Self {
+        Self {
+            tangent: Some(tangent.normalized()),
+            ..self
+        }
+    }
+
+    pub fn with_color(self, color: Color) -> Self {
+        Self {
+            color: Some(color),
+            ..self
         }
     }
 
@@ -0,0 +1,207 @@
+use crate::structures::{Vec3, Point3, Quaternion, Transform};
+
+use std::ops::{Mul, MulAssign};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Matrix4 {
+    pub m: [[f64; 4]; 4]
+}
+
+impl Matrix4 {
+    pub fn new(m: [[f64; 4]; 4]) -> Self {
+        Self {
+            m: m
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ])
+    }
+
+    pub fn from_columns(x: Vec3, y: Vec3, z: Vec3, translation: Vec3) -> Self {
+        Self::new([
+            [x.x, y.x, z.x, translation.x],
+            [x.y, y.y, z.y, translation.y],
+            [x.z, y.z, z.z, translation.z],
+            [0.0, 0.0, 0.0, 1.0]
+        ])
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::from_columns(Vec3::right(), Vec3::up(), Vec3::front(), translation)
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_columns(Vec3::new(scale.x, 0.0, 0.0), Vec3::new(0.0, scale.y, 0.0), Vec3::new(0.0, 0.0, scale.z), Vec3::zero())
+    }
+
+    pub fn from_rotation(rotation: Quaternion) -> Self {
+        let q = rotation.normalized();
+        let (x, y, z, w) = (q.v.x, q.v.y, q.v.z, q.s);
+
+        Self::new([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w), 0.0],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w), 0.0],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y), 0.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ])
+    }
+
+    pub fn from_transform(transform: &Transform) -> Self {
+        Self::from_translation(transform.translation) * Self::from_rotation(transform.rotation) * Self::from_scale(transform.scale)
+    }
+
+    pub fn column(&self, index: usize) -> Vec3 {
+        Vec3::new(self.m[0][index], self.m[1][index], self.m[2][index])
+    }
+
+    pub fn translation(&self) -> Vec3 {
+        self.column(3)
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut m = [[0.0; 4]; 4];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.m[j][i];
+            }
+        }
+
+        Self::new(m)
+    }
+
+    pub fn determinant(&self) -> f64 {
+        match self.decompose_lu() {
+            Some((lu, _, sign)) => (0..4).fold(sign, |determinant, i| determinant * lu[i][i]),
+            None => 0.0
+        }
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let (lu, permutation, _) = self.decompose_lu()?;
+        let mut inverse = [[0.0; 4]; 4];
+
+        for column in 0..4 {
+            let mut x = [0.0; 4];
+            for i in 0..4 {
+                let b = match permutation[i] == column {
+                    true => 1.0,
+                    false => 0.0
+                };
+                x[i] = (0..i).fold(b, |sum, j| sum - lu[i][j] * x[j]);
+            }
+
+            for i in (0..4).rev() {
+                x[i] = ((i + 1)..4).fold(x[i], |sum, j| sum - lu[i][j] * x[j]) / lu[i][i];
+            }
+
+            for (i, row) in inverse.iter_mut().enumerate() {
+                row[column] = x[i];
+            }
+        }
+
+        Some(Self::new(inverse))
+    }
+
+    pub fn transform_point(&self, point: Point3) -> Point3 {
+        self.transform_vector(point) + self.translation()
+    }
+
+    pub fn transform_vector(&self, vec: Vec3) -> Vec3 {
+        self.column(0) * vec.x + self.column(1) * vec.y + self.column(2) * vec.z
+    }
+
+    pub fn to_transform(&self) -> Option<Transform> {
+        let mut x = self.column(0);
+        let mut y = self.column(1);
+        let mut z = self.column(2);
+
+        let mut scale = Vec3::new(x.length(), 0.0, 0.0);
+        x /= scale.x;
+
+        let shear_xy = Vec3::dot(&x, &y);
+        y -= x * shear_xy;
+        scale.y = y.length();
+        y /= scale.y;
+
+        let (shear_xz, shear_yz) = (Vec3::dot(&x, &z), Vec3::dot(&y, &z));
+        z -= x * shear_xz + y * shear_yz;
+        scale.z = z.length();
+        z /= scale.z;
+
+        let shear = f64::max(shear_xy.abs() / scale.y, f64::max(shear_xz.abs(), shear_yz.abs()) / scale.z);
+        if shear.is_nan() || shear >= 1e-6 {
+            return None
+        }
+
+        if Vec3::dot(&Vec3::cross(&x, &y), &z) < 0.0 {
+            scale.x = -scale.x;
+            x = -x;
+        }
+
+        Some(Transform::new(self.translation(), Quaternion::from_rotation_matrix(&Self::from_columns(x, y, z, Vec3::zero())), scale))
+    }
+
+    fn decompose_lu(&self) -> Option<([[f64; 4]; 4], [usize; 4], f64)> {
+        let mut lu = self.m;
+        let mut permutation = [0, 1, 2, 3];
+        let mut sign = 1.0;
+
+        for k in 0..4 {
+            let pivot = (k..4).max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs())).unwrap();
+
+            if !lu[pivot][k].is_finite() || lu[pivot][k].abs() < 1e-12 {
+                return None
+            }
+
+            if pivot != k {
+                lu.swap(pivot, k);
+                permutation.swap(pivot, k);
+                sign = -sign;
+            }
+
+            for i in (k + 1)..4 {
+                lu[i][k] /= lu[k][k];
+                for j in (k + 1)..4 {
+                    lu[i][j] -= lu[i][k] * lu[k][j];
+                }
+            }
+        }
+
+        Some((lu, permutation, sign))
+    }
+}
+
+impl Default for Matrix4 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul for Matrix4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let mut m = [[0.0; 4]; 4];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| self.m[i][k] * other.m[k][j]).sum();
+            }
+        }
+
+        Self::new(m)
+    }
+}
+
+impl MulAssign for Matrix4 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = (*self) * other;
+    }
+}
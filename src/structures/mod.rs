@@ -10,6 +10,9 @@ pub use self::point3::Point3;
 pub mod ray;
 pub use self::ray::Ray;
 
+pub mod ray_visibility;
+pub use self::ray_visibility::RayVisibility;
+
 pub mod hit_record;
 pub use self::hit_record::HitRecord;
 
@@ -19,6 +22,9 @@ pub use self::image::Image;
 pub mod quaternion;
 pub use self::quaternion::Quaternion;
 
+pub mod matrix4;
+pub use self::matrix4::Matrix4;
+
 pub mod transform;
 pub use self::transform::Transform;
 
@@ -1,4 +1,4 @@
-use crate::structures::Vec3;
+use crate::structures::{Vec3, Matrix4};
 
 use std::ops::{Add, AddAssign, Sub, SubAssign, Div, DivAssign, Mul, MulAssign};
 
@@ -16,10 +16,51 @@ impl Quaternion {
         }
     }
 
+    pub fn identity() -> Self {
+        Quaternion::new(Vec3::zero(), 1.0)
+    }
+
     pub fn from_axis_angle(axis: Vec3, angle: f64) -> Self{
         Quaternion::new(axis, angle).as_unit_norm()
     }
 
+    pub fn from_euler(x: f64, y: f64, z: f64) -> Self {
+        let rotation_x = Quaternion::from_axis_angle(Vec3::right(), x);
+        let rotation_y = Quaternion::from_axis_angle(Vec3::up(), y);
+        let rotation_z = Quaternion::from_axis_angle(Vec3::front(), z);
+
+        rotation_z * rotation_y * rotation_x
+    }
+
+    pub fn look_rotation(forward: Vec3, up: Vec3) -> Self {
+        let z = forward.normalized();
+        let x = Vec3::cross(&up, &z).normalized();
+        let y = Vec3::cross(&z, &x);
+
+        Quaternion::from_rotation_matrix(&Matrix4::from_columns(x, y, z, Vec3::zero()))
+    }
+
+    pub fn from_rotation_matrix(matrix: &Matrix4) -> Self {
+        let m = &matrix.m;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let quaternion = if trace > 0.0 {
+            let s = 2.0 * f64::sqrt(trace + 1.0);
+            Quaternion::new(Vec3::new(m[2][1] - m[1][2], m[0][2] - m[2][0], m[1][0] - m[0][1]) / s, 0.25 * s)
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = 2.0 * f64::sqrt(1.0 + m[0][0] - m[1][1] - m[2][2]);
+            Quaternion::new(Vec3::new(0.25 * s, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s), (m[2][1] - m[1][2]) / s)
+        } else if m[1][1] > m[2][2] {
+            let s = 2.0 * f64::sqrt(1.0 + m[1][1] - m[0][0] - m[2][2]);
+            Quaternion::new(Vec3::new((m[0][1] + m[1][0]) / s, 0.25 * s, (m[1][2] + m[2][1]) / s), (m[0][2] - m[2][0]) / s)
+        } else {
+            let s = 2.0 * f64::sqrt(1.0 + m[2][2] - m[0][0] - m[1][1]);
+            Quaternion::new(Vec3::new((m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, 0.25 * s), (m[1][0] - m[0][1]) / s)
+        };
+
+        quaternion.normalized()
+    }
+
     pub fn slerp(a: Self, b: Self, t: f64) -> Self {
         let cos_half_angle = a.s * b.s + Vec3::dot(&a.v, &b.v);
         
@@ -49,6 +90,10 @@ impl Quaternion {
         }
     }
 
+    pub fn dot(a: &Self, b: &Self) -> f64 {
+        a.s * b.s + Vec3::dot(&a.v, &b.v)
+    }
+
     pub fn squared_norm(&self) -> f64 {
         self.s.powi(2) + self.v.squared_length()
     }
@@ -1,10 +1,11 @@
-use crate::structures::{Vec3, Point3};
+use crate::structures::{Vec3, Point3, RayVisibility};
 
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Ray {
     pub origin: Point3,
     pub direction: Vec3,
-    pub time: f64
+    pub time: f64,
+    pub kind: RayVisibility
 }
 
 impl Ray {
@@ -12,7 +13,8 @@ impl Ray {
         Self {
             origin: origin,
             direction: direction.normalized(),
-            time: 0.0
+            time: 0.0,
+            kind: RayVisibility::CAMERA
         }
     }
 
@@ -20,11 +22,30 @@ impl Ray {
         Self {
             origin: origin,
             direction: direction.normalized(),
-            time: time
+            time: time,
+            kind: RayVisibility::CAMERA
+        }
+    }
+
+    pub fn with_kind(self, kind: RayVisibility) -> Self {
+        Self {
+            kind: kind,
+            ..self
         }
     }
 
     pub fn at(&self, t: f64) -> Point3 {
         self.origin + t * self.direction
     }
-}
\ No newline at end of file
+}
+
+impl Default for Ray {
+    fn default() -> Self {
+        Self {
+            origin: Point3::default(),
+            direction: Vec3::default(),
+            time: 0.0,
+            kind: RayVisibility::CAMERA
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::ops::{BitOr, BitOrAssign};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct RayVisibility {
+    bits: u8
+}
+
+impl RayVisibility {
+    pub const NONE: Self = Self { bits: 0 };
+    pub const CAMERA: Self = Self { bits: 1 };
+    pub const SHADOW: Self = Self { bits: 1 << 1 };
+    pub const DIFFUSE: Self = Self { bits: 1 << 2 };
+    pub const GLOSSY: Self = Self { bits: 1 << 3 };
+    pub const TRANSMISSION: Self = Self { bits: 1 << 4 };
+    pub const ALL: Self = Self { bits: (1 << 5) - 1 };
+
+    pub fn contains(&self, other: Self) -> bool {
+        self.bits & other.bits == other.bits
+    }
+
+    pub fn intersects(&self, other: Self) -> bool {
+        self.bits & other.bits != 0
+    }
+
+    pub fn without(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits
+        }
+    }
+}
+
+impl Default for RayVisibility {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for RayVisibility {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        Self {
+            bits: self.bits | other.bits
+        }
+    }
+}
+
+impl BitOrAssign for RayVisibility {
+    fn bitor_assign(&mut self, other: Self) {
+        self.bits |= other.bits;
+    }
+}
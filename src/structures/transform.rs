@@ -1,4 +1,6 @@
-use crate::structures::{Vec3, Point3, Quaternion, Ray};
+use crate::structures::{Vec3, Point3, Quaternion, Matrix4, Ray};
+
+use std::ops::Mul;
 
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 pub struct Transform {
@@ -16,12 +18,35 @@ impl Transform {
         }
     }
 
+    pub fn identity() -> Self {
+        Self::new(Vec3::zero(), Quaternion::identity(), Vec3::new(1.0, 1.0, 1.0))
+    }
+
+    pub fn look_at(eye: Point3, target: Point3, up: Vec3) -> Self {
+        Self::new(eye, Quaternion::look_rotation(target - eye, up), Vec3::new(1.0, 1.0, 1.0))
+    }
+
+    pub fn from_matrix(matrix: &Matrix4) -> Option<Self> {
+        matrix.to_transform()
+    }
+
+    pub fn to_matrix(&self) -> Matrix4 {
+        Matrix4::from_transform(self)
+    }
+
+    pub fn inverse(&self) -> Matrix4 {
+        match self.to_matrix().inverse() {
+            Some(inverse) => inverse,
+            None => panic!("Transform with zero scale cannot be inverted.")
+        }
+    }
+
     pub fn transform_ray(&self, ray: Ray) -> Ray {
         Ray::with_time(
             self.transform_point(ray.origin), 
             self.transform_vector(ray.direction), 
             ray.time
-        )
+        ).with_kind(ray.kind)
     }
 
     pub fn transform_vector(&self, vec: Vec3) -> Vec3 {
@@ -32,25 +57,40 @@ impl Transform {
         self.transform_vector(point) + self.translation
     }
 
+    pub fn transform_normal(&self, normal: Vec3) -> Vec3 {
+        self.rotation.rotate_vector(normal * self.inverse_scale()).normalized()
+    }
+
     pub fn inverse_transform_ray(&self, ray: Ray) -> Ray {
         Ray::with_time(
             self.inverse_transform_point(ray.origin), 
             self.inverse_transform_vector(ray.direction), 
             ray.time
-        )
+        ).with_kind(ray.kind)
+    }
+
+    pub fn inverse_transform_ray_with_scale(&self, ray: Ray) -> (Ray, f64) {
+        let direction = self.inverse_transform_vector(ray.direction);
+        let scale = direction.length() / ray.direction.length();
+
+        (Ray::with_time(self.inverse_transform_point(ray.origin), direction, ray.time).with_kind(ray.kind), scale)
     }
 
     pub fn inverse_transform_vector(&self, vec: Vec3) -> Vec3 {
+        self.rotation.inverse().rotate_vector(vec) * self.inverse_scale()
+    }
+
+    pub fn inverse_transform_point(&self, point: Point3) -> Point3 {
+        self.inverse_transform_vector(point - self.translation)
+    }
+
+    fn inverse_scale(&self) -> Vec3 {
         let mut inv_scale = self.scale;
         for i in 0..3 {
             inv_scale[i] = 1.0 / inv_scale[i];
         }
 
-        self.rotation.inverse().rotate_vector(vec) * inv_scale
-    }
-
-    pub fn inverse_transform_point(&self, point: Point3) -> Point3 {
-        self.inverse_transform_vector(point - self.translation)
+        inv_scale
     }
 
     pub fn interpolate(a: Self, b: Self, t: f64) -> Self {
@@ -60,4 +100,12 @@ impl Transform {
             scale: Vec3::lerp(&a.scale, &b.scale, t)
         }
     }
-}
\ No newline at end of file
+}
+
+impl Mul for Transform {
+    type Output = Matrix4;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.to_matrix() * other.to_matrix()
+    }
+}
@@ -2,7 +2,9 @@ use std::ops::{Neg, Add, AddAssign, Sub, SubAssign, Div, DivAssign, Mul, MulAssi
 use std::default::Default;
 use std::f64::consts::PI;
 
-use rand::prelude::{thread_rng, Rng};
+use crate::random::thread_rng;
+
+use rand::prelude::Rng;
 
 #[derive(PartialEq, Clone, Copy, Default, Debug)]
 pub struct Vec3 {
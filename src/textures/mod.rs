@@ -11,7 +11,10 @@ pub mod checker;
 pub use self::checker::Checker;
 
 pub mod noise;
-pub use self::noise::Noise;
+pub use self::noise::{Noise, Perlin};
+
+pub mod vertex_color;
+pub use self::vertex_color::VertexColor;
 
 pub mod image_texture;
 pub use self::image_texture::ImageTexture;
\ No newline at end of file
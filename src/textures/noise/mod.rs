@@ -1,5 +1,5 @@
-mod perlin;
-use self::perlin::Perlin;
+pub mod perlin;
+pub use self::perlin::Perlin;
 
 pub mod noise;
 pub use self::noise::Noise;
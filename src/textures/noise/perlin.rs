@@ -1,6 +1,7 @@
 use crate::structures::{Vec3, Point3};
+use crate::random::thread_rng;
 
-use rand::prelude::{thread_rng, Rng};
+use rand::prelude::Rng;
 
 const POINT_COUNT: usize = 256;
 
@@ -119,4 +120,10 @@ impl Perlin {
 
         acc
     }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Self::new()
+    }
 }
\ No newline at end of file
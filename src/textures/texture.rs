@@ -1,5 +1,9 @@
-use crate::structures::{Point3, Color};
+use crate::structures::{Point3, Color, HitRecord};
 
 pub trait Texture: Send + Sync {
     fn value(&self, u: f64, v: f64, p: Point3) -> Color;
+
+    fn value_at_hit(&self, hit: &HitRecord) -> Color {
+        self.value(hit.u, hit.v, hit.point)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::textures::Texture;
+use crate::structures::{Point3, Color, HitRecord};
+
+use std::sync::Arc;
+
+pub struct VertexColor {
+    pub fallback: Arc<dyn Texture>
+}
+
+impl VertexColor {
+    pub fn new(fallback: Arc<dyn Texture>) -> Self {
+        Self {
+            fallback: fallback
+        }
+    }
+}
+
+impl Texture for VertexColor {
+    fn value(&self, u: f64, v: f64, p: Point3) -> Color {
+        self.fallback.value(u, v, p)
+    }
+
+    fn value_at_hit(&self, hit: &HitRecord) -> Color {
+        match hit.color {
+            Some(color) => color,
+            None => self.fallback.value_at_hit(hit)
+        }
+    }
+}
@@ -30,4 +30,123 @@ impl Clamp for f64 {
     fn clamp(v: Self, min: Self, max: Self) -> Self {
         f64::min(f64::max(v, min), max)
     }
-}
\ No newline at end of file
+}
+
+const ROOT_EPSILON: f64 = 1e-12;
+
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a.abs() < ROOT_EPSILON {
+        return match b.abs() < ROOT_EPSILON {
+            true => vec![],
+            false => vec![-c / b]
+        }
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return vec![]
+    }
+
+    let q = -0.5 * (b + f64::copysign(discriminant.sqrt(), b));
+    let mut roots = match q.abs() < ROOT_EPSILON {
+        true => vec![0.0],
+        false => vec![q / a, c / q]
+    };
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
+
+pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    if a.abs() < ROOT_EPSILON {
+        return solve_quadratic(b, c, d)
+    }
+
+    let (a, b, c) = (b / a, c / a, d / a);
+
+    let square_a = a * a;
+    let p = (-square_a / 3.0 + b) / 3.0;
+    let q = (2.0 / 27.0 * a * square_a - a * b / 3.0 + c) / 2.0;
+
+    let cube_p = p * p * p;
+    let discriminant = q * q + cube_p;
+
+    let mut roots = if discriminant.abs() < ROOT_EPSILON {
+        match q.abs() < ROOT_EPSILON {
+            true => vec![0.0],
+            false => {
+                let u = f64::cbrt(-q);
+                vec![2.0 * u, -u]
+            }
+        }
+    } else if discriminant < 0.0 {
+        let phi = f64::acos(f64::clamp(-q / f64::sqrt(-cube_p), -1.0, 1.0)) / 3.0;
+        let t = 2.0 * f64::sqrt(-p);
+        vec![t * phi.cos(), -t * (phi + std::f64::consts::PI / 3.0).cos(), -t * (phi - std::f64::consts::PI / 3.0).cos()]
+    } else {
+        let root = discriminant.sqrt();
+        vec![f64::cbrt(root - q) - f64::cbrt(root + q)]
+    };
+
+    for root in &mut roots {
+        *root -= a / 3.0;
+    }
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
+
+pub fn solve_quartic(a: f64, b: f64, c: f64, d: f64, e: f64) -> Vec<f64> {
+    if a.abs() < ROOT_EPSILON {
+        return solve_cubic(b, c, d, e)
+    }
+
+    let (a, b, c, d) = (b / a, c / a, d / a, e / a);
+
+    let square_a = a * a;
+    let p = -3.0 / 8.0 * square_a + b;
+    let q = square_a * a / 8.0 - a * b / 2.0 + c;
+    let r = -3.0 / 256.0 * square_a * square_a + square_a * b / 16.0 - a * c / 4.0 + d;
+
+    let mut roots = if r.abs() < ROOT_EPSILON {
+        let mut roots = solve_cubic(1.0, 0.0, p, q);
+        roots.push(0.0);
+        roots
+    } else {
+        let z = solve_cubic(1.0, -p / 2.0, -r, r * p / 2.0 - q * q / 8.0)[0];
+
+        let u = z * z - r;
+        let v = 2.0 * z - p;
+
+        if u < -ROOT_EPSILON || v < -ROOT_EPSILON {
+            return vec![]
+        }
+
+        let u = f64::max(u, 0.0).sqrt();
+        let v = match q < 0.0 {
+            true => -f64::max(v, 0.0).sqrt(),
+            false => f64::max(v, 0.0).sqrt()
+        };
+
+        let mut roots = solve_quadratic(1.0, v, z - u);
+        roots.extend(solve_quadratic(1.0, -v, z + u));
+        roots
+    };
+
+    for root in &mut roots {
+        *root -= a / 4.0;
+
+        for _ in 0..2 {
+            let value = (((*root + a) * *root + b) * *root + c) * *root + d;
+            let derivative = ((4.0 * *root + 3.0 * a) * *root + 2.0 * b) * *root + c;
+
+            if derivative.abs() > ROOT_EPSILON {
+                *root -= value / derivative;
+            }
+        }
+    }
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
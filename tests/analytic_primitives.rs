@@ -0,0 +1,111 @@
+use raytracer::hittables::{Capsule, Cone, Cylinder, Disk, Hittable, Paraboloid, Torus};
+use raytracer::structures::{Point3, Ray, Vec3};
+
+use std::f64::consts::PI;
+
+mod common;
+use common::{EPSILON, material, assert_close, assert_vec_close, assert_close_within, assert_vec_close_within};
+
+fn assert_hit(hittable: &dyn Hittable, ray: Ray, t: f64, normal: Vec3) {
+    let hit = hittable.hit(&ray, 0.001, f64::INFINITY).unwrap();
+    let aabb = hittable.bounding_box(0.0, 1.0).unwrap();
+
+    assert_close(hit.t, t);
+    assert_vec_close(hit.normal.normalized(), normal);
+    assert!((0.0..=1.0).contains(&hit.u) && (0.0..=1.0).contains(&hit.v));
+    assert!((0..3).all(|axis| aabb.min[axis] - EPSILON <= hit.point[axis] && hit.point[axis] <= aabb.max[axis] + EPSILON));
+    assert!(hittable.occluded(&ray, 0.001, t + 0.01));
+    assert!(!hittable.occluded(&ray, 0.001, t - 0.01));
+}
+
+fn assert_samples_on_surface<F: Fn() -> (Point3, Vec3)>(hittable: &dyn Hittable, sample_surface: F) {
+    for _ in 0..500 {
+        let (point, normal) = sample_surface();
+        let ray = Ray::new(point + 0.01 * normal, -normal);
+        let hit = hittable.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+        assert_close(normal.length(), 1.0);
+        assert_close_within(hit.t, 0.01, 1e-6);
+        assert_vec_close_within(hit.normal.normalized(), normal, 1e-4);
+    }
+}
+
+#[test]
+fn disk_and_annulus() {
+    let disk = Disk::new(Point3::new(0.0, 1.0, 0.0), 2.0, material());
+    assert_hit(&disk, Ray::new(Point3::new(1.5, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 4.0, Vec3::up());
+    assert!(disk.hit(&Ray::new(Point3::new(2.5, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).is_none());
+
+    let annulus = Disk::annulus(Point3::zero(), 2.0, 1.0, material());
+    assert!(annulus.hit(&Ray::new(Point3::new(0.5, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).is_none());
+    assert_close(annulus.area(), 3.0 * PI);
+    assert_samples_on_surface(&annulus, || annulus.sample_surface());
+}
+
+#[test]
+fn cylinder_body_and_caps() {
+    let open = Cylinder::new(Point3::zero(), 1.0, 2.0, false, material());
+    let capped = Cylinder::new(Point3::zero(), 1.0, 2.0, true, material());
+
+    assert_hit(&open, Ray::new(Point3::new(5.0, 1.0, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 4.0, Vec3::right());
+    assert_hit(&capped, Ray::new(Point3::new(0.5, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 3.0, Vec3::up());
+    assert!(open.hit(&Ray::new(Point3::new(0.5, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).is_none());
+    assert_hit(&open, Ray::new(Point3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)), 1.0, Vec3::new(0.0, 0.0, 1.0));
+
+    assert_close(open.area(), 4.0 * PI);
+    assert_close(capped.area(), 6.0 * PI);
+    assert_samples_on_surface(&capped, || capped.sample_surface());
+}
+
+#[test]
+fn cone_body_and_base() {
+    let cone = Cone::new(Point3::zero(), 1.0, 1.0, true, material());
+
+    assert_hit(&cone, Ray::new(Point3::new(5.0, 0.5, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 4.5, Vec3::new(1.0, 1.0, 0.0).normalized());
+    assert_hit(&cone, Ray::new(Point3::new(0.5, -5.0, 0.0), Vec3::new(0.0, 1.0, 0.0)), 5.0, Vec3::down());
+    assert!(cone.hit(&Ray::new(Point3::new(5.0, 1.5, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 0.001, f64::INFINITY).is_none());
+    assert_close(cone.hit(&Ray::new(Point3::new(0.5, -5.0, 0.0), Vec3::new(0.0, 1.0, 0.0)), 0.001, f64::INFINITY).unwrap().v, 0.5);
+
+    assert_close(cone.area(), PI * (2.0f64.sqrt() + 1.0));
+    assert_samples_on_surface(&cone, || cone.sample_surface());
+}
+
+#[test]
+fn torus_quartic_roots() {
+    let torus = Torus::new(Point3::zero(), 2.0, 0.5, material());
+
+    assert_hit(&torus, Ray::new(Point3::new(5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 2.5, Vec3::right());
+    assert_hit(&torus, Ray::new(Point3::new(2.0, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 4.5, Vec3::up());
+    assert_hit(&torus, Ray::new(Point3::new(1000.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 997.5, Vec3::right());
+    assert!(torus.hit(&Ray::new(Point3::new(0.0, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).is_none());
+
+    let inside = torus.hit(&Ray::new(Point3::zero(), Vec3::new(0.0, 0.0, 1.0)), 0.001, f64::INFINITY).unwrap();
+    assert_close(inside.t, 1.5);
+
+    assert_close(torus.area(), 4.0 * PI * PI);
+    assert_samples_on_surface(&torus, || torus.sample_surface());
+}
+
+#[test]
+fn capsule_spheres_and_body() {
+    let capsule = Capsule::new(Point3::zero(), 0.5, 2.0, material());
+
+    assert_hit(&capsule, Ray::new(Point3::new(0.0, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 2.5, Vec3::up());
+    assert_hit(&capsule, Ray::new(Point3::new(0.0, -5.0, 0.0), Vec3::new(0.0, 1.0, 0.0)), 4.5, Vec3::down());
+    assert_hit(&capsule, Ray::new(Point3::new(0.0, 1.0, 5.0), Vec3::new(0.0, 0.0, -1.0)), 4.5, Vec3::new(0.0, 0.0, 1.0));
+
+    assert_close(capsule.area(), 3.0 * PI);
+    assert_samples_on_surface(&capsule, || capsule.sample_surface());
+}
+
+#[test]
+fn paraboloid_body_and_cap() {
+    let paraboloid = Paraboloid::new(Point3::zero(), 1.0, 1.0, true, material());
+
+    assert_hit(&paraboloid, Ray::new(Point3::new(0.0, -5.0, 0.0), Vec3::new(0.0, 1.0, 0.0)), 5.0, Vec3::down());
+    assert_hit(&paraboloid, Ray::new(Point3::new(0.5, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 4.0, Vec3::up());
+    assert_hit(&paraboloid, Ray::new(Point3::new(5.0, 0.25, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 4.5, Vec3::new(1.0, -1.0, 0.0).normalized());
+
+    assert_close(paraboloid.area(), PI * (5.0f64.powf(1.5) - 1.0) / 6.0 + PI);
+    assert_samples_on_surface(&paraboloid, || paraboloid.sample_surface());
+}
@@ -0,0 +1,83 @@
+use raytracer::hittables::{AnimatedInstance, Hittable, Instance, Interpolation, Keyframe, MovingInstance, Sphere};
+use raytracer::structures::{Point3, Quaternion, Ray, Transform, Vec3};
+
+use std::f64::consts::FRAC_PI_2;
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, material, assert_vec_close};
+
+fn sphere() -> Arc<dyn Hittable> {
+    Arc::new(Sphere::new(Point3::zero(), 1.0, material()))
+}
+
+fn spinning_wheel(interpolation: Interpolation) -> AnimatedInstance {
+    let offset = Transform::new(Vec3::new(5.0, 0.0, 0.0), Quaternion::identity(), Vec3::new(1.0, 1.0, 1.0));
+    let spoke: Arc<dyn Hittable> = Arc::new(Instance::new(sphere(), offset));
+
+    let keyframes = (0..5).map(|i| {
+        let rotation = Quaternion::from_axis_angle(Vec3::front(), i as f64 * FRAC_PI_2);
+        Keyframe::new(i as f64 / 4.0, Transform::new(Vec3::zero(), rotation, Vec3::new(1.0, 1.0, 1.0)))
+    }).collect();
+
+    AnimatedInstance::new(spoke, keyframes, interpolation)
+}
+
+#[test]
+fn two_linear_keyframes_match_moving_instance() {
+    let transform_0 = Transform::new(Vec3::new(-1.0, 0.0, 2.0), Quaternion::identity(), Vec3::new(1.0, 1.0, 1.0));
+    let transform_1 = Transform::new(Vec3::new(3.0, 1.0, 0.0), Quaternion::from_axis_angle(Vec3::up(), 1.0), Vec3::new(2.0, 1.0, 1.0));
+
+    let moving = MovingInstance::new(sphere(), transform_0, transform_1, 0.0, 1.0);
+    let animated = AnimatedInstance::new(sphere(), vec![Keyframe::new(1.0, transform_1), Keyframe::new(0.0, transform_0)], Interpolation::Linear);
+
+    for i in 0..=10 {
+        let time = i as f64 / 10.0;
+        let point = Point3::new(0.5, -0.25, 1.0);
+        assert_vec_close(animated.transform_at(time).transform_point(point), moving.transform_at(time).transform_point(point));
+    }
+}
+
+#[test]
+fn cubic_interpolation_passes_through_keyframes() {
+    let wheel = spinning_wheel(Interpolation::Cubic);
+    let point = Point3::new(1.0, 2.0, 3.0);
+
+    for keyframe in wheel.keyframes() {
+        assert_vec_close(wheel.transform_at(keyframe.time).transform_point(point), keyframe.transform.transform_point(point));
+    }
+
+    assert_vec_close(wheel.transform_at(-1.0).transform_point(point), point);
+}
+
+#[test]
+fn bounds_cover_the_whole_motion() {
+    for interpolation in [Interpolation::Linear, Interpolation::Cubic] {
+        let wheel = spinning_wheel(interpolation);
+        let aabb = wheel.bounding_box(0.0, 1.0).unwrap();
+
+        assert!(aabb.max.x - aabb.min.x >= 12.0 && aabb.max.y - aabb.min.y >= 12.0);
+
+        for i in 0..=1000 {
+            let time = i as f64 / 1000.0;
+            let center = wheel.transform_at(time).transform_point(Point3::new(5.0, 0.0, 0.0));
+
+            for axis in 0..3 {
+                assert!(aabb.min[axis] <= center[axis] - 1.0 && center[axis] + 1.0 <= aabb.max[axis]);
+            }
+        }
+    }
+}
+
+#[test]
+fn rays_hit_the_object_at_their_own_time() {
+    let wheel = spinning_wheel(Interpolation::Linear);
+
+    let at_start = Ray::with_time(Point3::new(5.0, 0.0, 10.0), Vec3::back(), 0.0);
+    let at_quarter = Ray::with_time(Point3::new(0.0, 5.0, 10.0), Vec3::back(), 0.25);
+    let missed = Ray::with_time(Point3::new(5.0, 0.0, 10.0), Vec3::back(), 0.25);
+
+    assert!((wheel.hit(&at_start, 0.001, f64::INFINITY).unwrap().t - 9.0).abs() < EPSILON);
+    assert!((wheel.hit(&at_quarter, 0.001, f64::INFINITY).unwrap().t - 9.0).abs() < EPSILON);
+    assert!(wheel.hit(&missed, 0.001, f64::INFINITY).is_none());
+}
@@ -0,0 +1,113 @@
+use raytracer::hittables::{Hittable, HittableList, Sphere, BVHBuilder, FlatBVH};
+use raytracer::structures::{Point3, Ray, Vec3};
+
+use std::sync::Arc;
+
+mod common;
+use common::{material, assert_close};
+
+fn sphere_grid() -> HittableList {
+    let mut list = HittableList::new();
+    for i in 0..512 {
+        let (x, y, z) = ((i % 8) as f64, ((i / 8) % 8) as f64, (i / 64) as f64);
+        list.add(Arc::new(Sphere::new(Point3::new(3.0 * x, 3.0 * y + 0.1 * x, 3.0 * z), 0.5 + 0.05 * y, material())));
+    }
+
+    list
+}
+
+#[test]
+fn sah_builds_are_full_binary_trees_cheaper_than_a_single_leaf() {
+    let list = sphere_grid();
+    let primitives = list.hittables.len();
+
+    for max_leaf_size in [1, 4, 16] {
+        let builder = BVHBuilder::new(0.0, 1.0).with_max_leaf_size(max_leaf_size);
+        let build = builder.build(&list);
+
+        assert_eq!(build.node_count, 2 * build.leaf_count - 1);
+        assert!(build.leaf_count >= primitives / max_leaf_size);
+        assert!(build.sah_cost < builder.intersection_cost * primitives as f64);
+        assert!(build.max_depth >= 9);
+    }
+
+    let build = BVHBuilder::new(0.0, 1.0).with_max_leaf_size(1).build(&list);
+    assert_eq!(build.leaf_count, primitives);
+}
+
+#[test]
+fn sah_build_hits_match_a_linear_scan() {
+    let list = sphere_grid();
+    let build = BVHBuilder::new(0.0, 1.0).with_bin_count(8).build(&list);
+
+    for i in 0..200 {
+        let origin = Point3::new(-5.0, (i % 20) as f64 * 1.3 - 2.0, (i / 20) as f64 * 2.5 - 2.0);
+        let ray = Ray::new(origin, Vec3::new(1.0, 0.05 * (i % 7) as f64, 0.03 * (i % 5) as f64));
+
+        let expected = list.hit(&ray, 0.001, f64::INFINITY);
+        let actual = build.root.hit(&ray, 0.001, f64::INFINITY);
+
+        assert_eq!(actual.is_some(), expected.is_some());
+        assert_eq!(build.root.occluded(&ray, 0.001, f64::INFINITY), expected.is_some());
+        if let (Some(actual), Some(expected)) = (actual, expected) {
+            assert_close(actual.t, expected.t);
+        }
+    }
+}
+
+#[test]
+fn degenerate_splits_stay_within_the_traversal_stack() {
+    let mut list = HittableList::new();
+    for i in 0..400 {
+        list.add(Arc::new(Sphere::new(Point3::new(2.0f64.powi(i), 0.0, 0.0), 0.25 * 2.0f64.powi(i), material())));
+    }
+
+    let builder = BVHBuilder::new(0.0, 1.0).with_max_leaf_size(1);
+    let bvh = FlatBVH::with_builder(&list, &builder);
+    let ray = Ray::new(Point3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+
+    assert_close(bvh.hit(&ray, 0.001, f64::INFINITY).unwrap().t, 1.75);
+    assert!(bvh.occluded(&ray, 0.001, f64::INFINITY));
+    assert!(!bvh.occluded(&ray, 0.001, 1.5));
+    assert!(builder.build(&list).max_depth < 64);
+}
+
+#[test]
+fn depth_capped_subtrees_keep_leaves_within_the_flat_layout() {
+    let mut list = HittableList::new();
+    for i in 0..400 {
+        list.add(Arc::new(Sphere::new(Point3::new(2.0f64.powi(i), 0.0, 0.0), 0.25 * 2.0f64.powi(i), material())));
+    }
+    for _ in 0..70000 {
+        list.add(Arc::new(Sphere::new(Point3::new(0.5, 0.0, 0.0), 0.125, material())));
+    }
+
+    let builder = BVHBuilder::new(0.0, 1.0).with_max_leaf_size(1);
+    let bvh = FlatBVH::with_builder(&list, &builder);
+    let ray = Ray::new(Point3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+
+    assert_close(bvh.hit(&ray, 0.001, f64::INFINITY).unwrap().t, 1.375);
+    assert!(bvh.occluded(&Ray::new(Point3::new(0.5, -1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)), 0.001, f64::INFINITY));
+
+    let build = builder.build(&list);
+    assert!(build.max_depth < 64);
+    assert_eq!(build.leaf_count, list.hittables.len());
+}
+
+#[test]
+fn parallel_builds_match_sequential_builds() {
+    let mut list = HittableList::new();
+    for i in 0..10000 {
+        let (x, y, z) = ((i % 23) as f64, ((i * 7) % 31) as f64, ((i * 13) % 37) as f64);
+        list.add(Arc::new(Sphere::new(Point3::new(x + 0.01 * z, y, z), 0.3, material())));
+    }
+
+    let sequential = BVHBuilder::new(0.0, 1.0).with_threads(1).build(&list);
+    let parallel = BVHBuilder::new(0.0, 1.0).with_threads(4).build(&list);
+
+    assert_eq!(sequential.threads_used, 1);
+    assert!(parallel.threads_used > 1);
+    assert_eq!(parallel.node_count, sequential.node_count);
+    assert_eq!(parallel.leaf_count, sequential.leaf_count);
+    assert_close(parallel.sah_cost, sequential.sah_cost);
+}
@@ -0,0 +1,138 @@
+use raytracer::hittables::{Hittable, HittableList, Sphere};
+use raytracer::rendering::{render, Camera, Checkpoint, CheckpointParams, Film, Filter, Integrator, LightLinking, RenderParams};
+use raytracer::skyboxes::SolidColorSkybox;
+use raytracer::structures::{Color, Image, Point3, Vec3};
+
+use std::env;
+use std::fs;
+use std::sync::Arc;
+
+mod common;
+use common::material;
+
+fn checkpoint_path(name: &str) -> String {
+    let path = env::temp_dir().join(format!("raytracer_{}_{}.checkpoint", name, std::process::id()));
+    let path = path.to_str().unwrap().to_string();
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn params(num_samples: u32, checkpoint: Option<CheckpointParams>) -> RenderParams {
+    RenderParams {
+        image_width: 6,
+        image_height: 4,
+        num_samples: num_samples,
+        max_ray_depth: 4,
+        seed: 11,
+        checkpoint: checkpoint,
+        ..Default::default()
+    }
+}
+
+fn render_scene(params: &RenderParams) -> Image {
+    let mut world = HittableList::new();
+    world.add(Arc::new(Sphere::new(Point3::new(0.0, 0.0, -3.0), 1.0, material())));
+    let world: Arc<dyn Hittable> = Arc::new(world);
+
+    let camera = Arc::new(Camera::new(Point3::zero(), Point3::new(0.0, 0.0, -1.0), Vec3::up(), 1.0, 1.5, 0.0, 1.0, 0.0, 1.0));
+    let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.5, 0.7, 1.0)));
+
+    render(world, skybox, camera, params, |_, _| {}).image
+}
+
+#[test]
+fn checkpoints_survive_a_write_read_round_trip() {
+    let path = checkpoint_path("round_trip");
+    let mut film = Film::new(3, 2);
+    film.add_sample(&Filter::default(), 1.5, 0.5, Color::new(0.25, 0.5, 0.75));
+
+    let mut light_linking = LightLinking::new();
+    light_linking.exclude(1, &[2, 3]);
+
+    let checkpoint = Checkpoint {
+        seed: 42,
+        max_ray_depth: 7,
+        filter: Filter::default(),
+        integrator: Integrator::default(),
+        light_linking: light_linking,
+        completed_passes: 5,
+        film: film
+    };
+
+    checkpoint.write(&path).unwrap();
+    let read = Checkpoint::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(read.seed, 42);
+    assert_eq!(read.max_ray_depth, 7);
+    assert!(read.filter == checkpoint.filter);
+    assert!(read.integrator == checkpoint.integrator);
+    assert_eq!(read.light_linking, checkpoint.light_linking);
+    assert_eq!(read.completed_passes, 5);
+    assert_eq!((read.film.width, read.film.height), (3, 2));
+    assert_eq!(read.film.radiance, checkpoint.film.radiance);
+    assert_eq!(read.film.weights, checkpoint.film.weights);
+}
+
+#[test]
+fn resumed_renders_match_uninterrupted_renders() {
+    let path = checkpoint_path("resume");
+    let uninterrupted = render_scene(&params(8, None));
+
+    render_scene(&params(3, Some(CheckpointParams::new(&path, 1, true))));
+    assert_eq!(Checkpoint::read(&path).unwrap().completed_passes, 3);
+
+    let resumed = render_scene(&params(8, Some(CheckpointParams::new(&path, 1, true))));
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(resumed.buffer, uninterrupted.buffer);
+}
+
+#[test]
+fn corrupt_checkpoints_start_a_fresh_render() {
+    let path = checkpoint_path("corrupt");
+    fs::write(&path, b"RTCK garbage").unwrap();
+
+    let fresh = render_scene(&params(4, None));
+    let recovered = render_scene(&params(4, Some(CheckpointParams::new(&path, 0, true))));
+
+    assert_eq!(recovered.buffer, fresh.buffer);
+    assert_eq!(Checkpoint::read(&path).unwrap().completed_passes, 4);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn oversized_film_dimensions_are_rejected_before_allocating() {
+    let path = checkpoint_path("oversized");
+    render_scene(&params(2, Some(CheckpointParams::new(&path, 1, true))));
+
+    let mut bytes = fs::read(&path).unwrap();
+    let header = bytes.len() - 6 * 4 * 32 - 16;
+    assert_eq!(bytes[header..(header + 8)], 6u64.to_le_bytes());
+    bytes[header..(header + 8)].copy_from_slice(&(1u64 << 40).to_le_bytes());
+    bytes[(header + 8)..(header + 16)].copy_from_slice(&(1u64 << 20).to_le_bytes());
+    fs::write(&path, &bytes).unwrap();
+
+    assert!(Checkpoint::read(&path).is_err());
+
+    let fresh = render_scene(&params(4, None));
+    let recovered = render_scene(&params(4, Some(CheckpointParams::new(&path, 0, true))));
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(recovered.buffer, fresh.buffer);
+}
+
+#[test]
+fn checkpoints_with_different_light_linking_are_incompatible() {
+    let path = checkpoint_path("light_linking");
+    render_scene(&params(2, Some(CheckpointParams::new(&path, 1, true))));
+
+    let saved = Checkpoint::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let mut linked = params(8, None);
+    assert!(saved.is_compatible(&linked));
+
+    linked.light_linking.include(1, &[2]);
+    assert!(!saved.is_compatible(&linked));
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use raytracer::materials::{Lambertian, Material};
+use raytracer::structures::{Color, Vec3};
+use raytracer::textures::SolidColor;
+
+use std::sync::Arc;
+
+pub const EPSILON: f64 = 1e-9;
+
+pub fn material() -> Arc<dyn Material> {
+    Arc::new(Lambertian::new(Arc::new(SolidColor::new(Color::new(0.5, 0.5, 0.5)))))
+}
+
+pub fn assert_close(a: f64, b: f64) {
+    assert_close_within(a, b, EPSILON);
+}
+
+pub fn assert_vec_close(a: Vec3, b: Vec3) {
+    assert_vec_close_within(a, b, EPSILON);
+}
+
+pub fn assert_close_within(a: f64, b: f64, epsilon: f64) {
+    assert!((a - b).abs() < epsilon, "{} != {}", a, b);
+}
+
+pub fn assert_vec_close_within(a: Vec3, b: Vec3, epsilon: f64) {
+    assert!((a - b).length() < epsilon, "{:?} != {:?}", a, b);
+}
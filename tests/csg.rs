@@ -0,0 +1,97 @@
+use raytracer::hittables::{AABox, CSG, Hittable, HittableList, Instance, Quad, Sphere};
+use raytracer::materials::Material;
+use raytracer::structures::{Point3, Quaternion, Ray, Transform, Vec3};
+
+use std::f64::consts::FRAC_PI_2;
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, material};
+
+fn sphere(x: f64, radius: f64, material: Arc<dyn Material>) -> Arc<dyn Hittable> {
+    Arc::new(Sphere::new(Point3::new(x, 0.0, 0.0), radius, material))
+}
+
+fn along_x() -> Ray {
+    Ray::new(Point3::new(-10.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0))
+}
+
+fn distances(hittable: &dyn Hittable, ray: &Ray) -> Vec<f64> {
+    hittable.hit_all(ray, 0.001, f64::INFINITY).iter().map(|hit| hit.t).collect()
+}
+
+fn assert_distances(actual: Vec<f64>, expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len(), "{:?} != {:?}", actual, expected);
+    for (a, b) in actual.iter().zip(expected) {
+        assert!((a - b).abs() < EPSILON, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn union_merges_overlapping_intervals() {
+    let union = CSG::union(sphere(-0.5, 1.0, material()), sphere(0.5, 1.0, material()));
+
+    assert_distances(distances(&union, &along_x()), &[8.5, 11.5]);
+    assert_distances(distances(&union, &Ray::new(Point3::new(2.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0))), &[0.5, 3.5]);
+}
+
+#[test]
+fn intersection_keeps_overlap() {
+    let lens = CSG::intersection(sphere(-0.5, 1.0, material()), sphere(0.5, 1.0, material()));
+    let hit = lens.hit(&along_x(), 0.001, f64::INFINITY).unwrap();
+
+    assert_distances(distances(&lens, &along_x()), &[9.5, 10.5]);
+    assert!((hit.normal - Vec3::new(-1.0, 0.0, 0.0)).length() < EPSILON);
+    assert!(lens.hit(&Ray::new(Point3::new(-10.0, 0.9, 0.0), Vec3::new(1.0, 0.0, 0.0)), 0.001, f64::INFINITY).is_none());
+}
+
+#[test]
+fn difference_flips_cut_surface_normals() {
+    let cutter_material = material();
+    let difference = CSG::difference(sphere(0.0, 1.0, material()), sphere(1.0, 1.0, cutter_material.clone()));
+
+    let ray = Ray::new(Point3::new(10.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    let hit = difference.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert_distances(distances(&difference, &ray), &[10.0, 11.0]);
+    assert!((hit.normal - Vec3::new(1.0, 0.0, 0.0)).length() < EPSILON);
+    assert!(Arc::ptr_eq(&hit.material, &cutter_material));
+    assert!(hit.is_front_facing(&ray));
+}
+
+#[test]
+fn nested_operations_and_inside_origins() {
+    let block: Arc<dyn Hittable> = Arc::new(AABox::new(Point3::new(-2.0, -1.0, -1.0), Point3::new(2.0, 1.0, 1.0), material()));
+    let rotated: Arc<dyn Hittable> = Arc::new(Instance::new(block, Transform::new(Vec3::zero(), Quaternion::from_axis_angle(Vec3::right(), FRAC_PI_2), Vec3::new(1.0, 1.0, 1.0))));
+    let holes: Arc<dyn Hittable> = Arc::new(CSG::union(sphere(-1.0, 0.5, material()), sphere(1.0, 0.5, material())));
+    let part = CSG::difference(rotated, holes);
+
+    assert_distances(distances(&part, &along_x()), &[8.0, 8.5, 9.5, 10.5, 11.5, 12.0]);
+
+    let inside = Ray::new(Point3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    assert_distances(distances(&part, &inside), &[0.5, 1.5, 2.0]);
+    assert!(part.occluded(&inside, 0.001, 0.6));
+    assert!(!part.occluded(&inside, 0.001, 0.4));
+
+    let aabb = part.bounding_box(0.0, 1.0).unwrap();
+    assert!((aabb.max.x - 2.0).abs() < EPSILON);
+}
+
+#[test]
+fn tangent_crossings_do_not_drop_real_boundaries() {
+    let mut holes = HittableList::new();
+    holes.add(Arc::new(Sphere::new(Point3::new(-1.0, 1.0, 0.0), 1.0, material())));
+    holes.add(sphere(1.5, 0.5, material()));
+
+    let carved = CSG::difference(sphere(0.0, 3.0, material()), Arc::new(holes));
+
+    assert_distances(distances(&carved, &along_x()), &[7.0, 11.0, 12.0, 13.0]);
+}
+
+#[test]
+fn unmatched_crossings_extend_to_infinity() {
+    let wall = Arc::new(Quad::new(Point3::new(0.0, -5.0, -5.0), Vec3::new(0.0, 10.0, 0.0), Vec3::new(0.0, 0.0, 10.0), material()));
+    let cut = CSG::difference(sphere(0.0, 3.0, material()), wall);
+
+    assert_distances(distances(&cut, &along_x()), &[7.0, 10.0]);
+}
@@ -0,0 +1,106 @@
+use raytracer::hittables::{Curve, Hittable, Instance};
+use raytracer::materials::{Hair, Lambertian, Material};
+use raytracer::structures::{Color, Point3, Quaternion, Ray, Transform, Vec3};
+use raytracer::textures::SolidColor;
+
+use std::f64::consts::FRAC_PI_2;
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, material};
+
+fn straight() -> [Point3; 4] {
+    [Point3::new(0.0, 0.0, 0.0), Point3::new(1.0 / 3.0, 0.0, 0.0), Point3::new(2.0 / 3.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)]
+}
+
+fn arc() -> [Point3; 4] {
+    [Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.5), Point3::new(1.0, 1.0, -0.5), Point3::new(1.0, 2.0, 0.0)]
+}
+
+fn down(x: f64, z: f64) -> Ray {
+    Ray::new(Point3::new(x, 5.0, z), Vec3::new(0.0, -1.0, 0.0))
+}
+
+#[test]
+fn ribbon_faces_the_ray() {
+    let ribbon = Curve::ribbon(straight(), 0.2, 0.2, material());
+    let hit = ribbon.hit(&down(0.5, 0.05), 0.001, f64::INFINITY).unwrap();
+
+    assert!((hit.t - 5.0).abs() < EPSILON);
+    assert!((hit.u - 0.5).abs() < 1e-3);
+    assert!((hit.normal - Vec3::up()).length() < EPSILON);
+    assert!((hit.tangent.unwrap() - Vec3::right()).length() < EPSILON);
+
+    assert!(ribbon.hit(&down(0.5, 0.11), 0.001, f64::INFINITY).is_none());
+    assert!(ribbon.hit(&down(1.2, 0.0), 0.001, f64::INFINITY).is_none());
+    assert!(!ribbon.occluded(&down(0.5, 0.0), 0.001, 4.9));
+}
+
+#[test]
+fn round_curve_bends_normals_across_its_width() {
+    let round = Curve::round(straight(), 0.2, 0.2, material());
+    let hit = round.hit(&down(0.5, 0.09), 0.001, f64::INFINITY).unwrap();
+
+    assert!((hit.normal.z.abs() - 0.9).abs() < 1e-3);
+    assert!(hit.normal.y > 0.0);
+    assert!((hit.v - 0.5).abs() > 0.4);
+
+    let tapered = Curve::round(straight(), 0.2, 0.0, material());
+    assert!(tapered.hit(&down(0.1, 0.08), 0.001, f64::INFINITY).is_some());
+    assert!(tapered.hit(&down(0.9, 0.08), 0.001, f64::INFINITY).is_none());
+}
+
+#[test]
+fn curved_hits_lie_on_the_curve_and_inside_bounds() {
+    let curve = Curve::round(arc(), 0.1, 0.05, material());
+    let segments = curve.split(4);
+    let mut hits = 0;
+
+    for i in 0..200 {
+        let x = i as f64 / 200.0;
+        let ray = Ray::new(Point3::new(x, 1.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+
+        let whole = curve.hit(&ray, 0.001, f64::INFINITY);
+        let split = segments.iter().filter_map(|segment| segment.hit(&ray, 0.001, f64::INFINITY)).min_by(|a, b| a.t.partial_cmp(&b.t).unwrap());
+
+        if let Some(hit) = whole {
+            hits += 1;
+            let aabb = curve.bounding_box(0.0, 1.0).unwrap();
+            assert!((hit.point - curve.point_at(hit.u)).length() <= 0.5 * curve.width_at(hit.u) + 1e-3);
+            assert!((0..3).all(|axis| aabb.min[axis] <= hit.point[axis] && hit.point[axis] <= aabb.max[axis]));
+            assert!((split.expect("split segments missed").t - hit.t).abs() < 1e-3);
+        }
+    }
+
+    assert!(hits > 0);
+}
+
+#[test]
+fn instances_transform_tangents() {
+    let ribbon: Arc<dyn Hittable> = Arc::new(Curve::ribbon(straight(), 0.2, 0.2, material()));
+    let rotation = Transform::new(Vec3::zero(), Quaternion::from_axis_angle(Vec3::up(), FRAC_PI_2), Vec3::new(1.0, 1.0, 1.0));
+    let instance = Instance::new(ribbon, rotation);
+
+    let hit = instance.hit(&down(0.0, -0.5), 0.001, f64::INFINITY).unwrap();
+    assert!((hit.tangent.unwrap() - rotation.transform_vector(Vec3::right())).length() < EPSILON);
+}
+
+#[test]
+fn hair_scatters_without_gaining_energy() {
+    let hair = Hair::new(Arc::new(SolidColor::new(Color::new(1.0, 1.0, 1.0))), 0.3, 0.3);
+    let ribbon = Curve::round(straight(), 0.2, 0.2, Arc::new(Lambertian::new(Arc::new(SolidColor::new(Color::new(0.5, 0.5, 0.5))))));
+    let ray = down(0.5, 0.03);
+    let hit = ribbon.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    let samples = 2000;
+    let mut total = Color::new(0.0, 0.0, 0.0);
+    for _ in 0..samples {
+        let (scattered, attenuation) = hair.scatter(&ray, &hit).unwrap();
+        assert!((scattered.direction.length() - 1.0).abs() < EPSILON);
+        assert!(attenuation.x >= 0.0 && attenuation.x.is_finite());
+        total += attenuation;
+    }
+
+    let mean = total / samples as f64;
+    assert!(mean.x > 0.0 && mean.x <= 1.0 + EPSILON);
+}
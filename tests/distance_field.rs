@@ -0,0 +1,75 @@
+use raytracer::hittables::{DistanceField, Hittable, Sphere};
+use raytracer::sdf::{BoxSDF, Displacement, Mandelbulb, Repetition, SDF, SmoothUnion, SphereSDF, TorusSDF, Twist};
+use raytracer::structures::{AABB, Point3, Ray, Vec3};
+
+use std::sync::Arc;
+
+mod common;
+use common::{material};
+
+fn cube(half: f64) -> AABB {
+    AABB::new(Point3::new(-half, -half, -half), Point3::new(half, half, half))
+}
+
+#[test]
+fn sphere_tracing_matches_analytic_sphere() {
+    let field = DistanceField::new(Arc::new(SphereSDF::new(Point3::zero(), 1.0)), cube(1.1), material());
+    let sphere = Sphere::new(Point3::zero(), 1.0, material());
+
+    for &(origin, direction) in &[
+        (Point3::new(5.0, 0.3, -0.2), Vec3::new(-1.0, 0.0, 0.0)),
+        (Point3::new(2.0, 3.0, 1.0), Vec3::new(-0.5, -1.0, -0.3)),
+        (Point3::new(0.0, 0.0, 0.0), Vec3::new(0.2, 0.3, 1.0))
+    ] {
+        let ray = Ray::new(origin, direction);
+        let expected = sphere.hit(&ray, 0.001, f64::INFINITY).unwrap();
+        let hit = field.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+        assert!((hit.t - expected.t).abs() < 1e-4, "{} != {}", hit.t, expected.t);
+        assert!((hit.normal - expected.normal).length() < 1e-3);
+    }
+
+    assert!(field.hit(&Ray::new(Point3::new(5.0, 1.05, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 0.001, f64::INFINITY).is_none());
+    assert!(!field.occluded(&Ray::new(Point3::new(5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0)), 0.001, 3.9));
+}
+
+#[test]
+fn rays_leaving_the_surface_do_not_self_intersect() {
+    let field = DistanceField::new(Arc::new(SphereSDF::new(Point3::zero(), 1.0)), cube(1.1), material());
+    let hit = field.hit(&Ray::new(Point3::new(0.0, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).unwrap();
+
+    let grazing = Ray::new(hit.point, Vec3::new(1.0, 0.01, 0.0));
+    assert!(field.hit(&grazing, 0.001, f64::INFINITY).is_none());
+}
+
+#[test]
+fn operators_compose() {
+    let a: Arc<dyn SDF> = Arc::new(SphereSDF::new(Point3::new(-0.6, 0.0, 0.0), 0.5));
+    let b: Arc<dyn SDF> = Arc::new(SphereSDF::new(Point3::new(0.6, 0.0, 0.0), 0.5));
+    let blend = SmoothUnion::new(a.clone(), b.clone(), 0.3);
+    let gap = Point3::zero();
+
+    assert!(blend.distance(gap) < f64::min(a.distance(gap), b.distance(gap)));
+    assert!((SmoothUnion::new(a.clone(), b.clone(), 0.0).distance(gap) - 0.1).abs() < 1e-12);
+
+    let block: Arc<dyn SDF> = Arc::new(BoxSDF::new(Point3::zero(), Vec3::new(1.0, 2.0, 0.5)));
+    let twisted = Twist::new(block.clone(), 0.7);
+    assert!((twisted.distance(Point3::new(1.5, 0.0, 0.0)) - 0.5).abs() < 1e-12);
+
+    let repeated = Repetition::new(a.clone(), Vec3::new(4.0, 0.0, 0.0));
+    assert!((repeated.distance(Point3::new(7.4, 0.0, 0.0)) - a.distance(Point3::new(-0.6, 0.0, 0.0))).abs() < 1e-12);
+
+    let bumpy = Displacement::new(Arc::new(TorusSDF::new(Point3::zero(), 1.0, 0.25)), Arc::new(|p: Point3| 0.05 * (10.0 * p.x).sin()));
+    let field = DistanceField::new(Arc::new(bumpy), AABB::new(Point3::new(-1.4, -0.4, -1.4), Point3::new(1.4, 0.4, 1.4)), material()).with_step_scale(0.5);
+    assert!(field.hit(&Ray::new(Point3::new(0.0, 5.0, 1.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).is_some());
+}
+
+#[test]
+fn mandelbulb_is_hit_inside_its_bound() {
+    let field = DistanceField::new(Arc::new(Mandelbulb::default()), cube(1.5), material());
+    let hit = field.hit(&Ray::new(Point3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).unwrap();
+
+    assert!(hit.point.length() < 1.5);
+    assert!(Mandelbulb::default().distance(hit.point).abs() < 1e-3);
+    assert!(field.bounding_box(0.0, 1.0).is_some());
+}
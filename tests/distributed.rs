@@ -0,0 +1,130 @@
+use raytracer::hittables::{Hittable, HittableList, Sphere};
+use raytracer::rendering::{render, Camera, RenderHandle, RenderParams};
+use raytracer::rendering::distributed::{DistributedParams, render_distributed, serve_worker};
+use raytracer::skyboxes::{Skybox, SolidColorSkybox};
+use raytracer::structures::{Color, Point3, Vec3};
+
+use std::io::{self, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::thread;
+
+mod common;
+use common::{material, assert_vec_close};
+
+fn load_scene(scene: &[u8]) -> io::Result<(Arc<dyn Hittable>, Arc<dyn Skybox>, Arc<Camera>)> {
+    if scene != b"sphere" {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "unknown scene"))
+    }
+
+    let mut world = HittableList::new();
+    world.add(Arc::new(Sphere::new(Point3::new(0.0, 0.0, -3.0), 1.0, material())));
+    world.add(Arc::new(Sphere::new(Point3::new(0.0, -101.0, -3.0), 100.0, material())));
+
+    let camera = Arc::new(Camera::new(Point3::zero(), Point3::new(0.0, 0.0, -1.0), Vec3::up(), 1.0, 1.5, 0.0, 1.0, 0.0, 1.0));
+    let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.5, 0.7, 1.0)));
+
+    Ok((Arc::new(world), skybox, camera))
+}
+
+fn start_worker() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    thread::spawn(move || serve_worker(listener, &load_scene));
+    address
+}
+
+#[test]
+fn localhost_workers_match_a_local_render() {
+    let params = RenderParams {
+        image_width: 6,
+        image_height: 4,
+        num_samples: 10,
+        max_ray_depth: 4,
+        seed: 5,
+        ..Default::default()
+    };
+
+    let (world, skybox, camera) = load_scene(b"sphere").unwrap();
+    let local = render(world, skybox, camera, &params, |_, _| {});
+
+    let distributed = DistributedParams {
+        passes_per_task: 3,
+        ..DistributedParams::new(vec![start_worker(), start_worker()])
+    };
+    let handle = RenderHandle::new(&params);
+    let image = render_distributed(b"sphere", &params, &distributed, &handle, |_, _| {}).unwrap();
+
+    assert_eq!(handle.completed_passes(), 10);
+    for (color, expected) in image.buffer.iter().zip(&local.image.buffer) {
+        assert_vec_close(*color, *expected);
+    }
+
+    let statistics = handle.statistics();
+    assert_eq!(statistics.primary_rays, local.statistics.primary_rays);
+    assert_eq!(statistics.paths, local.statistics.paths);
+    assert_eq!(statistics.depth_histogram, local.statistics.depth_histogram);
+    assert!(!statistics.thread_times.is_empty());
+}
+
+#[test]
+fn unknown_scenes_fail_the_render() {
+    let params = RenderParams {
+        image_width: 2,
+        image_height: 2,
+        num_samples: 2,
+        ..Default::default()
+    };
+
+    let handle = RenderHandle::new(&params);
+    let distributed = DistributedParams::new(vec![start_worker(), start_worker()]);
+
+    assert!(render_distributed(b"missing", &params, &distributed, &handle, |_, _| {}).is_err());
+}
+
+fn start_lying_worker(width: u64, height: u64, pass_count: u32) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut reply = vec![];
+        reply.extend_from_slice(&4u32.to_le_bytes());
+        reply.extend_from_slice(&0u32.to_le_bytes());
+        reply.extend_from_slice(&pass_count.to_le_bytes());
+        reply.extend_from_slice(&width.to_le_bytes());
+        reply.extend_from_slice(&height.to_le_bytes());
+        if let Some(pixels) = width.checked_mul(height).filter(|&pixels| pixels < 1024) {
+            reply.resize(reply.len() + 32 * pixels as usize + 72, 0);
+        }
+
+        let _ = stream.write_all(&reply);
+        let _ = io::copy(&mut stream, &mut io::sink());
+    });
+
+    address
+}
+
+#[test]
+fn results_with_the_wrong_film_size_fail_the_worker() {
+    let params = RenderParams {
+        image_width: 6,
+        image_height: 4,
+        num_samples: 2,
+        ..Default::default()
+    };
+
+    for (width, height) in [(3, 3), (4, 6), (1 << 40, 1 << 20)] {
+        let handle = RenderHandle::new(&params);
+        let distributed = DistributedParams {
+            passes_per_task: 2,
+            ..DistributedParams::new(vec![start_lying_worker(width, height, 2)])
+        };
+
+        let error = render_distributed(b"sphere", &params, &distributed, &handle, |_, _| {}).err().unwrap();
+        assert!(error.to_string().contains("all workers failed"), "{}", error);
+        assert_eq!(handle.completed_passes(), 0);
+    }
+}
@@ -0,0 +1,69 @@
+use raytracer::rendering::{Film, Filter};
+use raytracer::structures::Color;
+
+mod common;
+use common::{assert_close, assert_vec_close};
+
+fn filters() -> Vec<Filter> {
+    vec![
+        Filter::box_filter(0.5),
+        Filter::triangle(1.0),
+        Filter::gaussian(1.5),
+        Filter::mitchell(2.0),
+        Filter::lanczos(2.0),
+        Filter::blackman_harris(1.5)
+    ]
+}
+
+#[test]
+fn filters_are_symmetric_and_vanish_outside_their_radius() {
+    for filter in filters() {
+        let radius = filter.radius();
+
+        assert!(filter.evaluate(0.0, 0.0) > 0.0, "{:?}", filter);
+        assert_eq!(filter.evaluate(radius + 0.01, 0.0), 0.0, "{:?}", filter);
+        assert_eq!(filter.evaluate(0.0, -radius - 0.01), 0.0, "{:?}", filter);
+
+        for &(x, y) in &[(0.3, 0.1), (0.7, -0.4), (-0.2, 0.45)] {
+            assert_close(filter.evaluate(x, y), filter.evaluate(-x, -y));
+            assert_close(filter.evaluate(x, y), filter.evaluate(y, x));
+        }
+    }
+}
+
+#[test]
+fn film_splats_sample_weights_across_the_filter_footprint() {
+    let mut film = Film::new(4, 4);
+    film.add_sample(&Filter::box_filter(0.5), 1.5, 2.5, Color::new(1.0, 0.0, 0.0));
+
+    assert_eq!(film.weights.iter().filter(|&&weight| weight > 0.0).count(), 1);
+    assert_close(film.weights[2 * 4 + 1], 1.0);
+
+    let mut film = Film::new(4, 4);
+    film.add_sample(&Filter::triangle(1.0), 2.0, 1.5, Color::new(2.0, 2.0, 2.0));
+
+    assert_close(film.weights[4 + 1], 0.5);
+    assert_close(film.weights[4 + 2], 0.5);
+    assert_close(film.weights.iter().sum::<f64>(), 1.0);
+    assert_vec_close(film.radiance[4 + 1], Color::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn constant_radiance_is_preserved_by_normalisation() {
+    let color = Color::new(0.25, 0.5, 0.75);
+
+    for filter in filters() {
+        let mut film = Film::new(5, 5);
+        for i in 0..40 {
+            let (fx, fy) = (0.37 * i as f64 % 5.0, 0.61 * i as f64 % 5.0);
+            film.add_sample(&filter, fx, fy, color);
+        }
+
+        let image = film.to_image();
+        for (pixel, &weight) in image.buffer.iter().zip(&film.weights) {
+            if weight > 1e-6 {
+                assert_vec_close(*pixel, color);
+            }
+        }
+    }
+}
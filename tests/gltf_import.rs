@@ -0,0 +1,169 @@
+use raytracer::hittables::Hittable;
+use raytracer::loaders::{GltfScene, parse_gltf, read_gltf};
+use raytracer::structures::{Color, HitRecord, Point3, Ray, Vec3};
+
+use image::ColorType;
+use image::codecs::png::PngEncoder;
+
+use std::fs;
+
+mod common;
+use common::{assert_close_within, assert_vec_close_within};
+
+const F32_EPSILON: f64 = 1e-6;
+
+fn triangle_buffer() -> Vec<u8> {
+    let positions = [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    let uvs = [0.0f32, 0.0, 1.0, 0.0, 0.0, 1.0];
+
+    positions.iter().chain(uvs.iter()).flat_map(|value| value.to_le_bytes()).collect()
+}
+
+fn red_blue_png() -> Vec<u8> {
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png).encode(&[255, 0, 0, 0, 0, 255], 2, 1, ColorType::Rgb8).unwrap();
+    png
+}
+
+fn document(material: &str, buffer_uri: Option<&str>, buffer_length: usize, image_length: usize) -> String {
+    let uri = buffer_uri.map_or(String::new(), |uri| format!("\"uri\": \"{}\", ", uri));
+    let (images, image_view) = match image_length {
+        0 => (String::new(), String::new()),
+        length => (
+            r#""textures": [{ "source": 0 }], "images": [{ "bufferView": 2, "mimeType": "image/png" }],"#.to_string(),
+            format!(", {{ \"buffer\": 0, \"byteOffset\": 60, \"byteLength\": {} }}", length)
+        )
+    };
+
+    format!(r#"{{
+        "asset": {{ "version": "2.0" }},
+        "scene": 0,
+        "scenes": [{{ "nodes": [0, 2] }}],
+        "nodes": [
+            {{ "name": "parent", "translation": [0, 0, -5], "children": [1] }},
+            {{ "name": "child", "mesh": 0, "scale": [2, 2, 2] }},
+            {{ "name": "eye", "camera": 0, "translation": [0, 1, 5] }}
+        ],
+        "cameras": [{{ "type": "perspective", "perspective": {{ "yfov": 0.8, "znear": 0.1 }} }}],
+        "meshes": [{{ "primitives": [{{ "attributes": {{ "POSITION": 0, "TEXCOORD_0": 1 }}, "material": 0 }}] }}],
+        "materials": [{}],
+        {}
+        "buffers": [{{ {}"byteLength": {} }}],
+        "bufferViews": [
+            {{ "buffer": 0, "byteOffset": 0, "byteLength": 36 }},
+            {{ "buffer": 0, "byteOffset": 36, "byteLength": 24 }}{}
+        ],
+        "accessors": [
+            {{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] }},
+            {{ "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2" }}
+        ]
+    }}"#, material, images, uri, buffer_length, image_view)
+}
+
+fn glb(material: &str, with_image: bool) -> Vec<u8> {
+    let mut bin = triangle_buffer();
+    let png = red_blue_png();
+    let image_length = match with_image {
+        true => png.len(),
+        false => 0
+    };
+    if with_image {
+        bin.extend(png);
+    }
+    let buffer_length = bin.len();
+
+    let mut json = document(material, None, buffer_length, image_length).into_bytes();
+    json.resize(json.len().div_ceil(4) * 4, b' ');
+    bin.resize(bin.len().div_ceil(4) * 4, 0);
+
+    let mut bytes = b"glTF".to_vec();
+    bytes.extend(2u32.to_le_bytes());
+    bytes.extend(((12 + 8 + json.len() + 8 + bin.len()) as u32).to_le_bytes());
+    bytes.extend((json.len() as u32).to_le_bytes());
+    bytes.extend(b"JSON");
+    bytes.extend(json);
+    bytes.extend((bin.len() as u32).to_le_bytes());
+    bytes.extend(b"BIN\0");
+    bytes.extend(bin);
+    bytes
+}
+
+fn hit_child(scene: &GltfScene, x: f64, y: f64) -> (Ray, HitRecord) {
+    let world = scene.graph.flatten();
+    let ray = Ray::new(Point3::new(x, y, 0.0), Vec3::new(0.0, 0.0, -1.0));
+    let hit = world.hit(&ray, 0.001, f64::INFINITY).unwrap();
+    (ray, hit)
+}
+
+#[test]
+fn node_hierarchy_becomes_instances() {
+    let scene = parse_gltf(&glb(r#"{ "pbrMetallicRoughness": { "metallicFactor": 0 } }"#, false), 1.5).unwrap();
+    let parent = scene.graph.find_path("parent").unwrap();
+    let child = scene.graph.find_path("parent/child").unwrap();
+
+    assert_eq!(scene.graph.node(child).parent(), Some(parent));
+    assert!(scene.graph.node(child).hittable.is_some());
+
+    let (ray, hit) = hit_child(&scene, 1.2, 0.6);
+    assert_close_within(hit.t, 5.0, F32_EPSILON);
+    assert_vec_close_within(hit.point, ray.at(5.0), F32_EPSILON);
+    assert!(scene.graph.flatten().hit(&Ray::new(Point3::new(1.2, 0.9, 0.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).is_none());
+}
+
+#[test]
+fn metallic_roughness_materials_are_converted() {
+    let diffuse = parse_gltf(&glb(r#"{ "pbrMetallicRoughness": { "baseColorFactor": [0.2, 0.4, 0.6, 1], "metallicFactor": 0 } }"#, false), 1.0).unwrap();
+    let (ray, hit) = hit_child(&diffuse, 0.5, 0.5);
+    let (_, attenuation) = hit.material.scatter(&ray, &hit).unwrap();
+    assert_vec_close_within(attenuation, Color::new(0.2, 0.4, 0.6), F32_EPSILON);
+
+    let mirror = parse_gltf(&glb(r#"{ "pbrMetallicRoughness": { "baseColorFactor": [0.9, 0.9, 0.9, 1], "metallicFactor": 1, "roughnessFactor": 0 } }"#, false), 1.0).unwrap();
+    let (ray, hit) = hit_child(&mirror, 0.5, 0.5);
+    let (scattered, attenuation) = hit.material.scatter(&ray, &hit).unwrap();
+    assert_vec_close_within(scattered.direction, Vec3::new(0.0, 0.0, 1.0), F32_EPSILON);
+    assert_vec_close_within(attenuation, Color::new(0.9, 0.9, 0.9), F32_EPSILON);
+
+    let light = parse_gltf(&glb(r#"{ "emissiveFactor": [1, 0.5, 0] }"#, false), 1.0).unwrap();
+    let (_, hit) = hit_child(&light, 0.5, 0.5);
+    assert_vec_close_within(hit.material.emitted(hit.u, hit.v, hit.point), Color::new(1.0, 0.5, 0.0), F32_EPSILON);
+}
+
+#[test]
+fn base_color_textures_use_embedded_images() {
+    let scene = parse_gltf(&glb(r#"{ "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 }, "baseColorFactor": [0.5, 0.5, 0.5, 1], "metallicFactor": 0 } }"#, true), 1.0).unwrap();
+
+    let (ray, hit) = hit_child(&scene, 0.4, 0.2);
+    assert_vec_close_within(hit.material.scatter(&ray, &hit).unwrap().1, Color::new(0.5, 0.0, 0.0), F32_EPSILON);
+
+    let (ray, hit) = hit_child(&scene, 1.4, 0.2);
+    assert_vec_close_within(hit.material.scatter(&ray, &hit).unwrap().1, Color::new(0.0, 0.0, 0.5), F32_EPSILON);
+}
+
+#[test]
+fn cameras_follow_their_nodes() {
+    let scene = parse_gltf(&glb(r#"{}"#, false), 1.5).unwrap();
+
+    assert_eq!(scene.cameras.len(), 1);
+    let camera = &scene.cameras[0];
+    assert_vec_close_within(camera.position, Point3::new(0.0, 1.0, 5.0), F32_EPSILON);
+    assert_vec_close_within(camera.z_axis, Vec3::new(0.0, 0.0, 1.0), F32_EPSILON);
+    assert_close_within(camera.vertical_fov, 0.8, F32_EPSILON);
+    assert_close_within(camera.aspect_ratio, 1.5, F32_EPSILON);
+}
+
+#[test]
+fn gltf_with_external_buffer_is_read_from_disk() {
+    let directory = std::env::temp_dir().join(format!("raytracer_gltf_{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("triangle.bin"), triangle_buffer()).unwrap();
+    fs::write(directory.join("triangle.gltf"), document(r#"{ "pbrMetallicRoughness": { "metallicFactor": 0 } }"#, Some("triangle.bin"), 60, 0)).unwrap();
+
+    let scene = read_gltf(directory.join("triangle.gltf").to_str().unwrap(), 1.0);
+    fs::remove_dir_all(&directory).unwrap();
+
+    let (_, hit) = hit_child(&scene.unwrap(), 0.5, 0.5);
+    assert_close_within(hit.t, 5.0, F32_EPSILON);
+
+    assert!(read_gltf("resources/missing.gltf", 1.0).is_err());
+    assert!(parse_gltf(b"not a gltf file", 1.0).is_err());
+}
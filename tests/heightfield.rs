@@ -0,0 +1,86 @@
+use raytracer::hittables::{Heightfield, Hittable, TriangleMesh};
+use raytracer::structures::{Color, Image, Point3, Ray, Vec3};
+use raytracer::textures::Perlin;
+
+mod common;
+use common::{EPSILON, material};
+
+fn bumpy_heights(resolution: usize) -> Vec<f64> {
+    (0..resolution * resolution).map(|i| {
+        let (x, z) = ((i % resolution) as f64, (i / resolution) as f64);
+        0.5 + 0.5 * f64::sin(0.7 * x) * f64::cos(0.4 * z)
+    }).collect()
+}
+
+#[test]
+fn grid_traversal_matches_tessellated_mesh() {
+    let resolution = 17;
+    let origin = Point3::new(-8.0, -1.0, -4.0);
+    let size = Vec3::new(16.0, 3.0, 8.0);
+    let heights = bumpy_heights(resolution);
+    let heightfield = Heightfield::new(heights.clone(), resolution, resolution, origin, size, material());
+
+    let positions = heights.iter().enumerate().map(|(i, height)| {
+        let (x, z) = ((i % resolution) as f64, (i / resolution) as f64);
+        origin + Vec3::new(x * size.x / 16.0, height * size.y, z * size.z / 16.0)
+    }).collect();
+    let mut indices = Vec::new();
+    for z in 0..resolution - 1 {
+        for x in 0..resolution - 1 {
+            let corner = z * resolution + x;
+            indices.push([corner, corner + 1, corner + resolution + 1]);
+            indices.push([corner, corner + resolution + 1, corner + resolution]);
+        }
+    }
+    let mesh = TriangleMesh::new(positions, indices, material());
+
+    let mut hits = 0;
+    for i in 0..400 {
+        let angle = i as f64 * 0.37;
+        let origin = Point3::new(12.0 * angle.cos(), 4.0 + (i % 7) as f64, 9.0 * angle.sin());
+        let target = Point3::new(((i * 13) % 16) as f64 - 8.0, 0.0, ((i * 7) % 8) as f64 - 4.0);
+        let ray = Ray::new(origin, target - origin);
+
+        let expected = mesh.hit(&ray, 0.001, f64::INFINITY);
+        let hit = heightfield.hit(&ray, 0.001, f64::INFINITY);
+
+        assert_eq!(hit.is_some(), expected.is_some(), "ray {}", i);
+        if let (Some(hit), Some(expected)) = (hit, expected) {
+            hits += 1;
+            assert!((hit.t - expected.t).abs() < 1e-6, "ray {}: {} != {}", i, hit.t, expected.t);
+            assert!(hit.normal.y > 0.0);
+            assert!((0.0..=1.0).contains(&hit.u) && (0.0..=1.0).contains(&hit.v));
+        }
+    }
+
+    assert!(hits > 300);
+}
+
+#[test]
+fn flat_heightfield_interpolates_uvs_and_heights() {
+    let heightfield = Heightfield::new(vec![0.5; 9], 3, 3, Point3::new(0.0, 0.0, 0.0), Vec3::new(100.0, 2.0, 50.0), material());
+    let hit = heightfield.hit(&Ray::new(Point3::new(25.0, 10.0, 40.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).unwrap();
+
+    assert!((hit.t - 9.0).abs() < EPSILON);
+    assert!((hit.normal - Vec3::up()).length() < EPSILON);
+    assert!((hit.u - 0.25).abs() < EPSILON && (hit.v - 0.8).abs() < EPSILON);
+
+    assert_eq!(heightfield.height_at(60.0, 10.0), Some(1.0));
+    assert_eq!(heightfield.height_at(-1.0, 10.0), None);
+    assert!(!heightfield.occluded(&Ray::new(Point3::new(25.0, 10.0, 40.0), Vec3::new(1.0, 0.0, 0.0)), 0.001, f64::INFINITY));
+}
+
+#[test]
+fn image_and_noise_sources() {
+    let mut image = Image::new(4, 3);
+    image[(2, 1)] = Color::new(1.0, 1.0, 1.0);
+
+    let from_image = Heightfield::from_image(&image, Point3::zero(), Vec3::new(3.0, 1.0, 2.0), material());
+    assert_eq!(from_image.resolution(), (4, 3));
+    assert_eq!(from_image.height_at(2.0, 1.0), Some(1.0));
+    assert_eq!(from_image.bounding_box(0.0, 1.0).unwrap().max.y, 1.0001);
+
+    let from_noise = Heightfield::from_noise(&Perlin::new(), 64, 64, 0.05, Point3::zero(), Vec3::new(1000.0, 50.0, 1000.0), material());
+    let hit = from_noise.hit(&Ray::new(Point3::new(500.0, 100.0, 500.0), Vec3::new(0.1, -1.0, 0.2)), 0.001, f64::INFINITY).unwrap();
+    assert!((hit.point.y - from_noise.height_at(hit.point.x, hit.point.z).unwrap()).abs() < 1.0);
+}
@@ -0,0 +1,101 @@
+use raytracer::hittables::{Hittable, HittableList, Instance, InstanceBVH, Sphere};
+use raytracer::structures::{Point3, Ray, Transform, Vec3};
+
+use std::sync::Arc;
+
+mod common;
+use common::{material, assert_close, assert_vec_close};
+
+fn translation(x: f64, y: f64, z: f64) -> Transform {
+    Transform { translation: Vec3::new(x, y, z), ..Transform::identity() }
+}
+
+fn row_of_spheres(count: usize) -> (Arc<dyn Hittable>, InstanceBVH) {
+    let sphere: Arc<dyn Hittable> = Arc::new(Sphere::new(Point3::zero(), 1.0, material()));
+    let instances = (0..count).map(|i| Instance::new(sphere.clone(), translation(3.0 * i as f64, 0.0, 0.0))).collect();
+
+    (sphere, InstanceBVH::new(instances, 0.0, 1.0))
+}
+
+fn downward_ray(x: f64, z: f64) -> Ray {
+    Ray::new(Point3::new(x, 100.0, z), Vec3::new(0.0, -1.0, 0.0))
+}
+
+#[test]
+fn refit_follows_moved_instances() {
+    let (_, mut bvh) = row_of_spheres(64);
+    assert!(!bvh.needs_refit());
+    assert_close(bvh.hit(&downward_ray(30.0, 0.0), 0.001, f64::INFINITY).unwrap().t, 99.0);
+
+    bvh.set_transform(10, translation(30.0, 0.0, 50.0));
+    assert!(bvh.needs_refit());
+    assert_vec_close(bvh.instance(10).transform.translation, Vec3::new(30.0, 0.0, 50.0));
+
+    bvh.refit();
+    assert!(!bvh.needs_refit());
+
+    assert!(bvh.hit(&downward_ray(30.0, 0.0), 0.001, f64::INFINITY).is_none());
+    assert!(!bvh.occluded(&downward_ray(30.0, 0.0), 0.001, f64::INFINITY));
+    assert_close(bvh.hit(&downward_ray(30.0, 50.0), 0.001, f64::INFINITY).unwrap().t, 99.0);
+    assert!(bvh.occluded(&downward_ray(30.0, 50.0), 0.001, f64::INFINITY));
+
+    let aabb = bvh.bounding_box(0.0, 1.0).unwrap();
+    assert_close(aabb.max.z, 51.0);
+    assert_close(aabb.min.z, -1.0);
+}
+
+#[test]
+fn refit_and_rebuild_match_a_linear_scan() {
+    let (sphere, mut bvh) = row_of_spheres(64);
+    let mut list = HittableList::new();
+
+    for i in 0..64 {
+        let transform = match i % 3 {
+            0 => translation(3.0 * i as f64, 0.0, (i % 7) as f64 * 4.0),
+            _ => translation(3.0 * i as f64, 0.0, 0.0)
+        };
+
+        bvh.set_transform(i, transform);
+        list.add(Arc::new(Instance::new(sphere.clone(), transform)));
+    }
+    bvh.refit();
+
+    let rebuilt = InstanceBVH::new((0..64).map(|i| Instance::new(sphere.clone(), bvh.instance(i).transform)).collect(), 0.0, 1.0);
+    let bvh = bvh.rebuild();
+
+    for i in 0..64 {
+        assert_eq!(bvh.instance(i).transform, rebuilt.instance(i).transform);
+    }
+
+    for x in 0..200 {
+        for z in 0..30 {
+            let ray = downward_ray(x as f64 - 2.3, z as f64 - 1.7);
+            let expected = list.hit(&ray, 0.001, f64::INFINITY).map(|hit| hit.t);
+
+            for bvh in [&bvh, &rebuilt] {
+                let actual = bvh.hit(&ray, 0.001, f64::INFINITY).map(|hit| hit.t);
+                assert_eq!(actual.is_some(), expected.is_some());
+                assert_eq!(bvh.occluded(&ray, 0.001, f64::INFINITY), expected.is_some());
+                if let (Some(actual), Some(expected)) = (actual, expected) {
+                    assert_close(actual, expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn empty_instance_bvhs_always_miss() {
+    let mut bvh = InstanceBVH::new(vec![], 0.0, 1.0);
+    let ray = downward_ray(0.0, 0.0);
+
+    assert!(bvh.is_empty());
+    assert!(bvh.hit(&ray, 0.001, f64::INFINITY).is_none());
+    assert!(!bvh.occluded(&ray, 0.001, f64::INFINITY));
+    assert!(bvh.bounding_box(0.0, 1.0).is_none());
+
+    bvh.refit();
+    let bvh = bvh.rebuild();
+    assert_eq!(bvh.len(), 0);
+    assert!(bvh.hit(&ray, 0.001, f64::INFINITY).is_none());
+}
@@ -0,0 +1,93 @@
+use raytracer::hittables::{AABox, Hittable, Instance, MovingInstance, Sphere};
+use raytracer::structures::{Point3, Quaternion, Ray, Transform, Vec3};
+
+use std::f64::consts::FRAC_PI_4;
+use std::sync::Arc;
+
+mod common;
+use common::{material, assert_close, assert_vec_close};
+
+fn scaling(x: f64, y: f64, z: f64) -> Transform {
+    Transform::new(Vec3::zero(), Quaternion::from_axis_angle(Vec3::up(), 0.0), Vec3::new(x, y, z))
+}
+
+fn stretched_sphere() -> Instance {
+    Instance::new(Arc::new(Sphere::new(Point3::zero(), 1.0, material())), scaling(2.0, 1.0, 1.0))
+}
+
+#[test]
+fn stretched_sphere_reports_world_distance() {
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    let hit = stretched_sphere().hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert_close(hit.t, 3.0);
+    assert_vec_close(hit.point, Point3::new(2.0, 0.0, 0.0));
+    assert_vec_close(hit.point, ray.at(hit.t));
+    assert_vec_close(hit.normal, Vec3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn stretched_sphere_normal_is_perpendicular_to_surface() {
+    let surface = Point3::new(f64::sqrt(2.0), f64::sqrt(0.5), 0.0);
+    let ray = Ray::new(surface + Vec3::new(5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    let hit = stretched_sphere().hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert_close(hit.t, 5.0);
+    assert_vec_close(hit.point, surface);
+    assert_vec_close(hit.normal, Vec3::new(1.0, 2.0, 0.0).normalized());
+}
+
+#[test]
+fn stretched_sphere_respects_world_t_max() {
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    let sphere = stretched_sphere();
+
+    assert!(sphere.hit(&ray, 0.001, 2.9).is_none());
+    assert!(!sphere.occluded(&ray, 0.001, 2.9));
+    assert!(sphere.hit(&ray, 0.001, 3.1).is_some());
+    assert!(sphere.occluded(&ray, 0.001, 3.1));
+}
+
+#[test]
+fn stretched_box_reports_world_distance() {
+    let aa_box = Arc::new(AABox::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0), material()));
+    let instance = Instance::new(aa_box, scaling(1.0, 3.0, 1.0));
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+    let hit = instance.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert_close(hit.t, 7.0);
+    assert_vec_close(hit.point, Point3::new(0.0, 3.0, 0.0));
+    assert_vec_close(hit.normal.normalized(), hit.normal);
+}
+
+#[test]
+fn rotated_box_under_non_uniform_scale_keeps_normals_perpendicular() {
+    let aa_box = Arc::new(AABox::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0), material()));
+    let rotation = Transform::new(Vec3::zero(), Quaternion::from_axis_angle(Vec3::front(), FRAC_PI_4), Vec3::new(1.0, 1.0, 1.0));
+    let instance = Instance::new(Arc::new(Instance::new(aa_box, rotation)), scaling(2.0, 1.0, 1.0));
+
+    let direction = Vec3::new(-1.0, -0.2, 0.0);
+    let first = instance.hit(&Ray::new(Point3::new(10.0, 1.0, 0.0), direction), 0.001, f64::INFINITY).unwrap();
+    let second = instance.hit(&Ray::new(Point3::new(10.0, 1.1, 0.0), direction), 0.001, f64::INFINITY).unwrap();
+
+    assert_vec_close(first.normal, second.normal);
+    assert_close(Vec3::dot(&first.normal, &(second.point - first.point)), 0.0);
+    assert_close(Vec3::dot(&first.normal, &Vec3::new(0.0, 0.0, 1.0)), 0.0);
+}
+
+#[test]
+fn moving_instance_matches_static_instance() {
+    let sphere: Arc<dyn Hittable> = Arc::new(Sphere::new(Point3::zero(), 1.0, material()));
+    let transform = scaling(1.0, 0.5, 3.0);
+    let moving = MovingInstance::new(sphere.clone(), transform, transform, 0.0, 1.0);
+    let instance = Instance::new(sphere, transform);
+
+    let ray = Ray::with_time(Point3::new(4.0, 0.2, -6.0), Vec3::new(-1.0, 0.0, 1.5), 0.5);
+    let expected = instance.hit(&ray, 0.001, f64::INFINITY).unwrap();
+    let hit = moving.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert_close(hit.t, expected.t);
+    assert_vec_close(hit.point, expected.point);
+    assert_vec_close(hit.normal, expected.normal);
+    assert_vec_close(hit.point, ray.at(hit.t));
+}
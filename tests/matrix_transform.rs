@@ -0,0 +1,128 @@
+use raytracer::hittables::{Hittable, MatrixInstance, Sphere};
+use raytracer::materials::Lambertian;
+use raytracer::structures::{Color, Matrix4, Point3, Quaternion, Ray, Transform, Vec3};
+use raytracer::textures::SolidColor;
+
+use std::f64::consts::FRAC_PI_2;
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, assert_vec_close};
+
+fn sample_transform() -> Transform {
+    Transform::new(Vec3::new(1.0, -2.0, 3.0), Quaternion::from_axis_angle(Vec3::new(1.0, 2.0, -0.5), 0.8), Vec3::new(2.0, 0.5, 1.5))
+}
+
+#[test]
+fn matrix_matches_transform() {
+    let transform = sample_transform();
+    let matrix = transform.to_matrix();
+    let point = Point3::new(0.3, -1.2, 4.0);
+
+    assert_vec_close(matrix.transform_point(point), transform.transform_point(point));
+    assert_vec_close(matrix.transform_vector(point), transform.transform_vector(point));
+}
+
+#[test]
+fn inverse_round_trips() {
+    let matrix = sample_transform().to_matrix();
+    let inverse = matrix.inverse().unwrap();
+    let point = Point3::new(-3.0, 0.5, 2.0);
+
+    assert_vec_close(inverse.transform_point(matrix.transform_point(point)), point);
+    assert!((matrix.determinant() * inverse.determinant() - 1.0).abs() < EPSILON);
+    assert!(Matrix4::from_scale(Vec3::new(1.0, 0.0, 1.0)).inverse().is_none());
+}
+
+#[test]
+fn decomposition_round_trips() {
+    let transform = sample_transform();
+    let decomposed = Transform::from_matrix(&transform.to_matrix()).unwrap();
+    let point = Point3::new(1.0, 1.0, -1.0);
+
+    assert_vec_close(decomposed.translation, transform.translation);
+    assert_vec_close(decomposed.scale, transform.scale);
+    assert_vec_close(decomposed.transform_point(point), transform.transform_point(point));
+}
+
+#[test]
+fn composition_applies_right_to_left() {
+    let parent = Transform::new(Vec3::new(0.0, 5.0, 0.0), Quaternion::from_axis_angle(Vec3::up(), FRAC_PI_2), Vec3::new(2.0, 2.0, 2.0));
+    let child = Transform::new(Vec3::new(1.0, 0.0, 0.0), Quaternion::from_axis_angle(Vec3::front(), 0.3), Vec3::new(1.0, 1.0, 1.0));
+    let point = Point3::new(0.5, 0.25, -1.0);
+
+    assert_vec_close((parent * child).transform_point(point), parent.transform_point(child.transform_point(point)));
+    assert_vec_close((Transform::identity() * child).transform_point(point), child.transform_point(point));
+    assert_vec_close((child.to_matrix() * child.inverse()).transform_point(point), point);
+}
+
+#[test]
+fn composition_and_inverse_keep_non_uniform_scale_shear() {
+    let parent = Transform::new(Vec3::zero(), Quaternion::identity(), Vec3::new(3.0, 1.0, 1.0));
+    let child = Transform::new(Vec3::zero(), Quaternion::from_axis_angle(Vec3::front(), 0.7), Vec3::new(1.0, 1.0, 1.0));
+    let point = Point3::new(0.0, 1.0, 0.0);
+
+    assert_vec_close((parent * child).transform_point(point), Point3::new(-3.0 * f64::sin(0.7), f64::cos(0.7), 0.0));
+
+    let transform = sample_transform();
+    let point = Point3::new(0.3, 1.0, -2.0);
+    assert_vec_close(transform.inverse().transform_point(transform.transform_point(point)), point);
+    assert_vec_close(transform.inverse().transform_point(point), transform.inverse_transform_point(point));
+}
+
+#[test]
+fn sheared_or_degenerate_matrices_cannot_become_a_transform() {
+    let parent = Transform::new(Vec3::zero(), Quaternion::identity(), Vec3::new(3.0, 1.0, 1.0));
+    let child = Transform::new(Vec3::zero(), Quaternion::from_axis_angle(Vec3::front(), 0.7), Vec3::new(1.0, 1.0, 1.0));
+
+    assert!(Transform::from_matrix(&(parent * child)).is_none());
+    assert!(Matrix4::from_scale(Vec3::new(1.0, 0.0, 1.0)).to_transform().is_none());
+    assert!(Matrix4::from_scale(Vec3::zero()).to_transform().is_none());
+
+    let mut nan = Matrix4::identity();
+    nan.m[1][1] = f64::NAN;
+    assert!(nan.to_transform().is_none());
+    assert!(nan.inverse().is_none());
+}
+
+#[test]
+fn euler_angles_apply_x_then_y_then_z() {
+    let euler = Quaternion::from_euler(0.1, 0.2, 0.3);
+    let composed = Quaternion::from_axis_angle(Vec3::front(), 0.3) * Quaternion::from_axis_angle(Vec3::up(), 0.2) * Quaternion::from_axis_angle(Vec3::right(), 0.1);
+    let vector = Vec3::new(1.0, 2.0, 3.0);
+
+    assert_vec_close(euler.rotate_vector(vector), composed.rotate_vector(vector));
+    assert_vec_close(Quaternion::identity().rotate_vector(vector), vector);
+}
+
+#[test]
+fn look_at_points_front_at_target() {
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let target = Point3::new(-4.0, 0.0, 7.0);
+    let transform = Transform::look_at(eye, target, Vec3::up());
+
+    assert_vec_close(transform.transform_point(Point3::zero()), eye);
+    assert_vec_close(transform.transform_vector(Vec3::front()), (target - eye).normalized());
+    assert!(transform.transform_vector(Vec3::right()).y.abs() < EPSILON);
+}
+
+#[test]
+fn sheared_instance_hits_at_world_distance() {
+    let material = Arc::new(Lambertian::new(Arc::new(SolidColor::new(Color::new(0.5, 0.5, 0.5)))));
+    let sphere = Arc::new(Sphere::new(Point3::zero(), 1.0, material));
+    let shear = Matrix4::new([
+        [1.0, 0.5, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0]
+    ]);
+    let instance = MatrixInstance::new(sphere, shear);
+
+    let ray = Ray::new(Point3::new(0.6, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+    let hit = instance.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert!((hit.t - 4.2).abs() < EPSILON);
+    assert_vec_close(hit.point, ray.at(hit.t));
+    assert_vec_close(hit.normal, Vec3::new(0.6, -0.3, 0.8).normalized());
+    assert!(Vec3::dot(&hit.normal, &shear.transform_vector(Vec3::up())).abs() < EPSILON);
+}
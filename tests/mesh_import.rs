@@ -0,0 +1,161 @@
+use raytracer::hittables::Hittable;
+use raytracer::loaders::{parse_ply, parse_stl};
+use raytracer::materials::{Lambertian, Material};
+use raytracer::structures::{Color, Point3, Ray, Vec3};
+use raytracer::textures::{SolidColor, VertexColor};
+
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, material, assert_vec_close};
+
+fn ascii_quad() -> String {
+    [
+        "ply",
+        "format ascii 1.0",
+        "comment unit quad",
+        "element vertex 4",
+        "property float x",
+        "property float y",
+        "property float z",
+        "property float nx",
+        "property float ny",
+        "property float nz",
+        "property float s",
+        "property float t",
+        "property uchar red",
+        "property uchar green",
+        "property uchar blue",
+        "element face 1",
+        "property list uchar int vertex_indices",
+        "end_header",
+        "0 0 0 0 0 1 0 0 255 0 0",
+        "1 0 0 0 0 1 1 0 255 0 0",
+        "1 1 0 0 0 1 1 1 0 0 255",
+        "0 1 0 0 0 1 0 1 0 0 255",
+        "4 0 1 2 3"
+    ].join("\n")
+}
+
+fn binary_triangle(little_endian: bool) -> Vec<u8> {
+    let format = match little_endian {
+        true => "binary_little_endian",
+        false => "binary_big_endian"
+    };
+    let header = format!("ply\nformat {} 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\nelement face 1\nproperty list uchar uint vertex_indices\nend_header\n", format);
+
+    let mut bytes = header.into_bytes();
+    for value in [0.0f32, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0] {
+        bytes.extend(match little_endian {
+            true => value.to_le_bytes(),
+            false => value.to_be_bytes()
+        });
+    }
+
+    bytes.push(3);
+    for index in [0u32, 1, 2] {
+        bytes.extend(match little_endian {
+            true => index.to_le_bytes(),
+            false => index.to_be_bytes()
+        });
+    }
+
+    bytes
+}
+
+#[test]
+fn ascii_ply_reads_attributes_and_triangulates_polygons() {
+    let mesh = parse_ply(ascii_quad().as_bytes(), material()).unwrap();
+
+    assert_eq!(mesh.vertex_count(), 4);
+    assert_eq!(mesh.triangle_count(), 2);
+    assert_eq!(mesh.uvs().unwrap()[2], (1.0, 1.0));
+    assert_vec_close(mesh.normals().unwrap()[0], Vec3::new(0.0, 0.0, 1.0));
+    assert_vec_close(mesh.colors().unwrap()[3], Color::new(0.0, 0.0, 1.0));
+
+    let hit = mesh.hit(&Ray::new(Point3::new(0.3, 0.6, 5.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).unwrap();
+
+    assert!((hit.t - 5.0).abs() < EPSILON);
+    assert!((hit.u - 0.3).abs() < EPSILON && (hit.v - 0.6).abs() < EPSILON);
+    assert_vec_close(hit.color.unwrap(), Color::new(0.4, 0.0, 0.6));
+}
+
+#[test]
+fn vertex_colors_drive_lambertian_albedo() {
+    let fallback = Arc::new(SolidColor::new(Color::new(0.0, 1.0, 0.0)));
+    let lambertian: Arc<dyn Material> = Arc::new(Lambertian::new(Arc::new(VertexColor::new(fallback))));
+    let mesh = parse_ply(ascii_quad().as_bytes(), lambertian).unwrap();
+
+    let ray = Ray::new(Point3::new(0.3, 0.6, 5.0), Vec3::new(0.0, 0.0, -1.0));
+    let hit = mesh.hit(&ray, 0.001, f64::INFINITY).unwrap();
+    let (_, attenuation) = hit.material.scatter(&ray, &hit).unwrap();
+
+    assert_vec_close(attenuation, Color::new(0.4, 0.0, 0.6));
+
+    let uncolored = parse_ply(binary_triangle(true).as_slice(), hit.material.clone()).unwrap();
+    let hit = uncolored.hit(&Ray::new(Point3::new(0.5, 0.5, 1.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).unwrap();
+    let (_, attenuation) = hit.material.scatter(&ray, &hit).unwrap();
+
+    assert!(hit.color.is_none());
+    assert_vec_close(attenuation, Color::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn binary_ply_reads_both_byte_orders() {
+    for little_endian in [true, false] {
+        let mesh = parse_ply(binary_triangle(little_endian).as_slice(), material()).unwrap();
+
+        assert_eq!(mesh.triangle_count(), 1);
+        assert!(mesh.uvs().is_none() && mesh.normals().is_none() && mesh.colors().is_none());
+
+        let hit = mesh.hit(&Ray::new(Point3::new(0.5, 0.5, 1.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).unwrap();
+        assert!((hit.t - 1.0).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn ascii_and_binary_stl_weld_shared_vertices() {
+    let ascii = "solid quad\n\
+        facet normal 0 0 1\n outer loop\n  vertex 0 0 0\n  vertex 1 0 0\n  vertex 1 1 0\n endloop\nendfacet\n\
+        facet normal 0 0 1\n outer loop\n  vertex 0 0 0\n  vertex 1 1 0\n  vertex 0 1 0\n endloop\nendfacet\n\
+        endsolid quad\n";
+
+    let mut binary = vec![0u8; 80];
+    binary.extend(2u32.to_le_bytes());
+    for triangle in [[[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]], [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]]] {
+        binary.extend([0.0f32, 0.0, 1.0].iter().flat_map(|value| value.to_le_bytes()));
+        binary.extend(triangle.iter().flatten().flat_map(|value| value.to_le_bytes()));
+        binary.extend([0u8, 0]);
+    }
+
+    for bytes in [ascii.as_bytes(), binary.as_slice()] {
+        let mesh = parse_stl(bytes, material()).unwrap();
+
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.triangle_count(), 2);
+        assert!(mesh.hit(&Ray::new(Point3::new(0.2, 0.7, 1.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).is_some());
+    }
+}
+
+#[test]
+fn malformed_files_are_rejected() {
+    assert!(parse_ply("obj\n".as_bytes(), material()).is_err());
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "3 0 1 7").as_bytes(), material()).is_err());
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "4 0 1").as_bytes(), material()).is_err());
+    assert!(parse_ply(&binary_triangle(true)[..binary_triangle(true).len() - 2], material()).is_err());
+    assert!(parse_stl(b"solid empty\nendsolid empty\n", material()).is_err());
+}
+
+#[test]
+fn garbage_list_counts_and_indices_are_rejected() {
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "1e30 0 1 2").as_bytes(), material()).is_err());
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "-4 0 1 2 3").as_bytes(), material()).is_err());
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "4 0 1 -2 3").as_bytes(), material()).is_err());
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "4 0 1 2.5 3").as_bytes(), material()).is_err());
+    assert!(parse_ply(ascii_quad().replace("4 0 1 2 3", "4 0 1 nan 3").as_bytes(), material()).is_err());
+
+    let mut huge_count = binary_triangle(true);
+    let count = huge_count.len() - 13;
+    huge_count[count] = 255;
+    assert!(parse_ply(&huge_count[..], material()).is_err());
+}
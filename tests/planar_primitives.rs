@@ -0,0 +1,131 @@
+use raytracer::hittables::{Hittable, HittableList, Plane, Quad, Sphere, Triangle, BVHBuilder, FlatBVH};
+use raytracer::structures::{Point3, Ray, Vec3};
+
+use std::sync::Arc;
+
+mod common;
+use common::{material, assert_close};
+
+#[test]
+fn axis_aligned_rects_keep_their_normals_and_uvs() {
+    let rects = [
+        (Quad::xy_rect(-1.0, 3.0, 0.0, 2.0, 1.0, material()), Point3::new(0.0, 1.0, 5.0), Vec3::front(), (0.25, 0.5)),
+        (Quad::xz_rect(-1.0, 3.0, 0.0, 2.0, 1.0, material()), Point3::new(0.0, 5.0, 1.0), Vec3::up(), (0.25, 0.5)),
+        (Quad::yz_rect(-1.0, 3.0, 0.0, 2.0, 1.0, material()), Point3::new(5.0, 0.0, 1.0), Vec3::right(), (0.25, 0.5))
+    ];
+
+    for (rect, origin, normal, (u, v)) in rects {
+        let hit = rect.hit(&Ray::new(origin, -normal), 0.001, f64::INFINITY).unwrap();
+
+        assert_close(hit.t, 4.0);
+        assert_close(hit.u, u);
+        assert_close(hit.v, v);
+        assert_close(Vec3::dot(&hit.normal, &normal), 1.0);
+        assert!(rect.hit(&Ray::new(origin + 4.0 * Vec3::new(1.0, 1.0, 1.0), -normal), 0.001, f64::INFINITY).is_none());
+    }
+}
+
+#[test]
+fn parallelogram_respects_edges() {
+    let quad = Quad::new(Point3::zero(), Vec3::new(2.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), material());
+    let down = Vec3::new(0.0, 0.0, -1.0);
+
+    let hit = quad.hit(&Ray::new(Point3::new(2.0, 0.5, 3.0), down), 0.001, f64::INFINITY).unwrap();
+    assert_close(hit.t, 3.0);
+    assert_close(hit.u, 0.75);
+    assert_close(hit.v, 0.5);
+
+    assert!(quad.hit(&Ray::new(Point3::new(0.2, 0.5, 3.0), down), 0.001, f64::INFINITY).is_none());
+    assert!(quad.occluded(&Ray::new(Point3::new(1.0, 0.5, 3.0), down), 0.001, 3.1));
+    assert!(!quad.occluded(&Ray::new(Point3::new(1.0, 0.5, 3.0), down), 0.001, 2.9));
+    assert_close(quad.area(), 2.0);
+
+    let aabb = quad.bounding_box(0.0, 1.0).unwrap();
+    assert!(aabb.min.x < 0.0 && aabb.max.x > 3.0 && aabb.max.y > 1.0 && aabb.min.z < 0.0 && aabb.max.z > 0.0);
+}
+
+#[test]
+fn triangle_interpolates_uvs() {
+    let triangle = Triangle::new(Point3::zero(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0), material())
+        .with_uvs([(0.0, 0.0), (2.0, 0.0), (0.0, 4.0)]);
+
+    let hit = triangle.hit(&Ray::new(Point3::new(0.25, 0.5, 1.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).unwrap();
+    assert_close(hit.t, 1.0);
+    assert_close(hit.u, 0.5);
+    assert_close(hit.v, 2.0);
+    assert_close(hit.normal.z, 1.0);
+
+    assert!(triangle.hit(&Ray::new(Point3::new(0.6, 0.6, 1.0), Vec3::new(0.0, 0.0, -1.0)), 0.001, f64::INFINITY).is_none());
+    assert_close(triangle.area(), 0.5);
+}
+
+#[test]
+fn surface_samples_lie_on_quads_and_triangles() {
+    let quad = Quad::xz_rect(-1.0, 3.0, 0.0, 2.0, 1.0, material());
+    let triangle = Triangle::new(Point3::zero(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 1.0), material());
+
+    let samplers: [(&dyn Hittable, &dyn Fn() -> (Point3, Vec3)); 2] = [
+        (&quad, &|| quad.sample_surface()),
+        (&triangle, &|| triangle.sample_surface())
+    ];
+
+    assert_close(quad.area(), 8.0);
+    assert_close(triangle.area(), 0.5 * 2.0f64.sqrt());
+
+    for (hittable, sample_surface) in samplers {
+        for _ in 0..200 {
+            let (point, normal) = sample_surface();
+            let hit = hittable.hit(&Ray::new(point + normal, -normal), 0.001, f64::INFINITY).unwrap();
+
+            assert_close(hit.t, 1.0);
+            assert_close(Vec3::dot(&hit.normal, &normal), 1.0);
+        }
+    }
+
+    assert_close(quad.sample_surface().1.y, 1.0);
+}
+
+#[test]
+fn plane_is_infinite() {
+    let plane = Plane::new(Point3::new(0.0, -1.0, 0.0), Vec3::new(0.0, 2.0, 0.0), material());
+
+    let hit = plane.hit(&Ray::new(Point3::new(1000.0, 4.0, -3000.0), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).unwrap();
+    assert_close(hit.t, 5.0);
+    assert_close(hit.normal.y, 1.0);
+    assert!((0.0..1.0).contains(&hit.u) && (0.0..1.0).contains(&hit.v));
+
+    assert!(plane.hit(&Ray::new(Point3::zero(), Vec3::new(1.0, 0.0, 0.0)), 0.001, f64::INFINITY).is_none());
+    assert!(plane.bounding_box(0.0, 1.0).is_none());
+}
+
+#[test]
+fn planes_stay_outside_the_bvh() {
+    let mut world = HittableList::new();
+    world.add(Arc::new(Plane::new(Point3::new(0.0, -1.0, 0.0), Vec3::up(), material())));
+    world.add(Arc::new(Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0, material())));
+    world.add(Arc::new(Sphere::new(Point3::new(3.0, 0.0, -5.0), 1.0, material())));
+
+    let flat = FlatBVH::new(&world, 0.0, 1.0);
+    let built = BVHBuilder::new(0.0, 1.0).build(&world).root;
+
+    assert_eq!(flat.primitive_count(), 3);
+    assert_eq!(flat.unbounded_count(), 1);
+    assert!(flat.bounding_box(0.0, 1.0).is_none());
+
+    let sphere_ray = Ray::new(Point3::new(3.0, 0.0, 0.0), Vec3::new(0.0, 0.0, -1.0));
+    let floor_ray = Ray::new(Point3::new(30.0, 4.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+
+    for bvh in [&flat as &dyn Hittable, built.as_ref()] {
+        assert_close(bvh.hit(&sphere_ray, 0.001, f64::INFINITY).unwrap().t, 4.0);
+        assert_close(bvh.hit(&floor_ray, 0.001, f64::INFINITY).unwrap().t, 5.0);
+        assert!(bvh.occluded(&floor_ray, 0.001, 5.5));
+        assert!(!bvh.occluded(&floor_ray, 0.001, 4.5));
+    }
+
+    let mut floor = HittableList::new();
+    floor.add(Arc::new(Plane::new(Point3::zero(), Vec3::up(), material())));
+
+    let flat = FlatBVH::new(&floor, 0.0, 1.0);
+    assert_eq!(flat.node_count(), 0);
+    assert_close(flat.hit(&floor_ray, 0.001, f64::INFINITY).unwrap().t, 4.0);
+}
@@ -0,0 +1,145 @@
+use raytracer::hittables::{Hittable, HittableList, Instance, ObjectProperties, Sphere};
+use raytracer::materials::{Dieletric, DiffuseLight, Material, Metal};
+use raytracer::rendering::{render, Camera, Integrator, LightLinking, RenderParams};
+use raytracer::skyboxes::SolidColorSkybox;
+use raytracer::structures::{Color, Point3, Quaternion, Ray, RayVisibility, Transform, Vec3};
+use raytracer::textures::SolidColor;
+
+use std::sync::Arc;
+
+mod common;
+use common::material;
+
+fn sphere(center: Point3, radius: f64, material: Arc<dyn Material>) -> Arc<dyn Hittable> {
+    Arc::new(Sphere::new(center, radius, material))
+}
+
+fn ray_of_kind(kind: RayVisibility) -> Ray {
+    Ray::new(Point3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0)).with_kind(kind)
+}
+
+#[test]
+fn visibility_masks_combine_flags() {
+    let mask = RayVisibility::CAMERA | RayVisibility::SHADOW;
+
+    assert!(mask.contains(RayVisibility::SHADOW));
+    assert!(!mask.intersects(RayVisibility::DIFFUSE));
+    assert!(RayVisibility::ALL.without(RayVisibility::CAMERA).intersects(RayVisibility::GLOSSY | RayVisibility::CAMERA));
+    assert!(!RayVisibility::ALL.without(RayVisibility::CAMERA).contains(RayVisibility::CAMERA));
+    assert_eq!(RayVisibility::NONE | RayVisibility::ALL, RayVisibility::ALL);
+    assert_eq!(Ray::new(Point3::zero(), Vec3::up()).kind, RayVisibility::CAMERA);
+}
+
+#[test]
+fn hidden_objects_still_cast_shadows() {
+    let object = ObjectProperties::new(sphere(Point3::zero(), 1.0, material()))
+        .with_visibility(RayVisibility::ALL.without(RayVisibility::CAMERA))
+        .with_id(7);
+
+    assert!(object.hit(&ray_of_kind(RayVisibility::CAMERA), 0.001, f64::INFINITY).is_none());
+    assert!(!object.occluded(&ray_of_kind(RayVisibility::CAMERA), 0.001, f64::INFINITY));
+    assert!(object.occluded(&ray_of_kind(RayVisibility::SHADOW), 0.001, f64::INFINITY));
+    assert_eq!(object.hit(&ray_of_kind(RayVisibility::DIFFUSE), 0.001, f64::INFINITY).unwrap().object_id, Some(7));
+
+    let transform = Transform::new(Vec3::new(0.0, 0.0, -1.0), Quaternion::identity(), Vec3::new(2.0, 2.0, 2.0));
+    let instance = Instance::new(Arc::new(object), transform);
+
+    assert!(instance.hit(&ray_of_kind(RayVisibility::CAMERA), 0.001, f64::INFINITY).is_none());
+    assert!(instance.hit(&ray_of_kind(RayVisibility::GLOSSY), 0.001, f64::INFINITY).is_some());
+}
+
+#[test]
+fn materials_tag_scattered_rays() {
+    let ray = ray_of_kind(RayVisibility::CAMERA);
+    let scatter = |material: Arc<dyn Material>| {
+        let hit = sphere(Point3::zero(), 1.0, material).hit(&ray, 0.001, f64::INFINITY).unwrap();
+        hit.material.scatter(&ray, &hit).unwrap().0.kind
+    };
+
+    assert_eq!(scatter(material()), RayVisibility::DIFFUSE);
+    assert_eq!(scatter(Arc::new(Metal::new(Arc::new(SolidColor::new(Color::new(0.9, 0.9, 0.9))), 0.0))), RayVisibility::GLOSSY);
+    assert!((RayVisibility::GLOSSY | RayVisibility::TRANSMISSION).contains(scatter(Arc::new(Dieletric::new(1.5)))));
+}
+
+#[test]
+fn light_links_include_or_exclude_receivers() {
+    let mut linking = LightLinking::new();
+    linking.include(1, &[10, 11]);
+    linking.exclude(2, &[10]);
+
+    assert!(linking.illuminates(Some(1), Some(11)));
+    assert!(!linking.illuminates(Some(1), Some(12)));
+    assert!(!linking.illuminates(Some(1), None));
+    assert!(!linking.illuminates(Some(2), Some(10)));
+    assert!(linking.illuminates(Some(2), None));
+    assert!(linking.illuminates(Some(3), Some(10)));
+    assert!(linking.illuminates(None, Some(10)));
+
+    linking.remove(1);
+    assert!(linking.illuminates(Some(1), Some(12)));
+}
+
+#[test]
+fn excluded_receivers_are_not_lit() {
+    let mut world = HittableList::new();
+    world.add(Arc::new(ObjectProperties::new(sphere(Point3::new(0.0, 0.0, -3.0), 1.5, material())).with_id(2)));
+    world.add(Arc::new(ObjectProperties::new(sphere(Point3::new(0.0, 0.0, 6.0), 4.0, Arc::new(DiffuseLight::new(Arc::new(SolidColor::new(Color::new(4.0, 4.0, 4.0))))))).with_id(1)));
+    let world: Arc<dyn Hittable> = Arc::new(world);
+
+    let camera = Arc::new(Camera::new(Point3::zero(), Point3::new(0.0, 0.0, -1.0), Vec3::up(), 0.5, 1.0, 0.0, 1.0, 0.0, 1.0));
+    let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.0, 0.0, 0.0)));
+
+    let brightness = |light_linking: LightLinking| {
+        let params = RenderParams {
+            image_width: 4,
+            image_height: 4,
+            num_samples: 8,
+            max_ray_depth: 4,
+            light_linking: light_linking,
+            ..Default::default()
+        };
+
+        let image = render(world.clone(), skybox.clone(), camera.clone(), &params, |_, _| {}).image;
+        image.buffer.iter().map(|color| color.x + color.y + color.z).sum::<f64>()
+    };
+
+    let mut excluded = LightLinking::new();
+    excluded.exclude(1, &[2]);
+    let mut included_elsewhere = LightLinking::new();
+    included_elsewhere.include(1, &[3]);
+
+    assert!(brightness(LightLinking::new()) > 0.0);
+    assert_eq!(brightness(excluded), 0.0);
+    assert_eq!(brightness(included_elsewhere), 0.0);
+}
+
+#[test]
+fn ambient_occlusion_only_counts_shadow_visible_occluders() {
+    let camera = Arc::new(Camera::new(Point3::zero(), Point3::new(0.0, 0.0, -1.0), Vec3::up(), 0.5, 1.0, 0.0, 1.0, 0.0, 1.0));
+    let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.0, 0.0, 0.0)));
+
+    let brightness = |occluder: Option<RayVisibility>| {
+        let mut world = HittableList::new();
+        world.add(sphere(Point3::new(0.0, 0.0, -3.0), 1.0, material()));
+        if let Some(visibility) = occluder {
+            world.add(Arc::new(ObjectProperties::new(sphere(Point3::new(0.0, 0.0, -1.5), 0.3, material())).with_visibility(visibility)));
+        }
+
+        let params = RenderParams {
+            image_width: 4,
+            image_height: 4,
+            num_samples: 16,
+            integrator: Integrator::ambient_occlusion(10.0),
+            ..Default::default()
+        };
+
+        let image = render(Arc::new(world), skybox.clone(), camera.clone(), &params, |_, _| {}).image;
+        image.buffer.iter().map(|color| color.x + color.y + color.z).sum::<f64>()
+    };
+
+    let unoccluded = brightness(None);
+
+    assert!(unoccluded > 0.0);
+    assert!(brightness(Some(RayVisibility::ALL.without(RayVisibility::CAMERA))) < unoccluded);
+    assert_eq!(brightness(Some(RayVisibility::ALL.without(RayVisibility::CAMERA).without(RayVisibility::SHADOW))), unoccluded);
+}
@@ -0,0 +1,81 @@
+use raytracer::hittables::{Hittable, HittableList, Sphere};
+use raytracer::rendering::{render_with_handle, Camera, RenderHandle, RenderParams};
+use raytracer::skyboxes::{Skybox, SolidColorSkybox};
+use raytracer::structures::{Color, Point3, Vec3};
+
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+use common::material;
+
+fn scene() -> (Arc<dyn Hittable>, Arc<dyn Skybox>, Arc<Camera>) {
+    let mut world = HittableList::new();
+    world.add(Arc::new(Sphere::new(Point3::new(0.0, 0.0, -3.0), 1.0, material())));
+
+    let camera = Arc::new(Camera::new(Point3::zero(), Point3::new(0.0, 0.0, -1.0), Vec3::up(), 1.0, 1.0, 0.0, 1.0, 0.0, 1.0));
+    let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.5, 0.7, 1.0)));
+
+    (Arc::new(world), skybox, camera)
+}
+
+fn params(num_samples: u32, time_budget: Option<Duration>) -> RenderParams {
+    RenderParams {
+        image_width: 8,
+        image_height: 8,
+        num_samples: num_samples,
+        max_ray_depth: 4,
+        time_budget: time_budget,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn cancelling_before_the_render_skips_every_pass() {
+    let (world, skybox, camera) = scene();
+    let params = params(16, None);
+    let handle = RenderHandle::new(&params);
+    handle.cancel();
+
+    let result = render_with_handle(world, skybox, camera, &params, &handle, |_, _| panic!("no pass should complete"));
+
+    assert!(handle.is_cancelled());
+    assert_eq!(handle.completed_passes(), 0);
+    assert_eq!(result.statistics.primary_rays, 0);
+    assert!(result.image.buffer.iter().all(|&color| color == Color::zero()));
+}
+
+#[test]
+fn cancelling_from_progress_stops_the_render_early() {
+    let (world, skybox, camera) = scene();
+    let params = params(5000, None);
+    let handle = RenderHandle::new(&params);
+    let canceller = handle.clone();
+
+    let result = render_with_handle(world, skybox, camera, &params, &handle, move |completed, _| {
+        if completed >= 2 {
+            canceller.cancel();
+        }
+    });
+
+    let completed = handle.completed_passes();
+    assert!(completed >= 2 && completed < params.num_samples);
+    assert!(result.image.buffer.iter().all(|color| color.x > 0.0));
+}
+
+#[test]
+fn time_budget_limits_the_passes_rendered() {
+    let (world, skybox, camera) = scene();
+
+    let exhausted = params(16, Some(Duration::ZERO));
+    let handle = RenderHandle::new(&exhausted);
+    render_with_handle(world.clone(), skybox.clone(), camera.clone(), &exhausted, &handle, |_, _| {});
+    assert_eq!(handle.completed_passes(), 0);
+
+    let limited = params(100_000, Some(Duration::from_millis(200)));
+    let handle = RenderHandle::new(&limited);
+    render_with_handle(world, skybox, camera, &limited, &handle, |_, _| {});
+
+    assert!(handle.completed_passes() < limited.num_samples);
+    assert!(!handle.is_cancelled());
+}
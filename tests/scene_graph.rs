@@ -0,0 +1,103 @@
+use raytracer::hittables::{Hittable, Sphere, BVHBuilder};
+use raytracer::scene::SceneGraph;
+use raytracer::structures::{Point3, Quaternion, Ray, Transform, Vec3};
+
+use std::f64::consts::FRAC_PI_2;
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, material, assert_vec_close};
+
+fn sphere() -> Arc<dyn Hittable> {
+    Arc::new(Sphere::new(Point3::zero(), 1.0, material()))
+}
+
+fn translation(x: f64, y: f64, z: f64) -> Transform {
+    Transform::new(Vec3::new(x, y, z), Quaternion::identity(), Vec3::new(1.0, 1.0, 1.0))
+}
+
+#[test]
+fn child_transforms_compose_with_parents() {
+    let mut graph = SceneGraph::new();
+    let rotation = Transform::new(Vec3::new(0.0, 2.0, 0.0), Quaternion::from_axis_angle(Vec3::up(), FRAC_PI_2), Vec3::new(2.0, 2.0, 2.0));
+    let arm = graph.add_node(graph.root(), "arm", rotation);
+    let hand = graph.add_object(arm, "hand", sphere(), translation(1.0, 0.0, 0.0));
+
+    let world = graph.world_matrix(hand);
+    assert_vec_close(world.transform_point(Point3::zero()), rotation.transform_point(Point3::new(1.0, 0.0, 0.0)));
+    assert_eq!(graph.find_path("arm/hand"), Some(hand));
+    assert_eq!(graph.find("hand"), Some(hand));
+    assert_eq!(graph.find_path("arm/foot"), None);
+
+    let flattened = graph.flatten();
+    assert_eq!(flattened.hittables.len(), 1);
+
+    let center = world.transform_point(Point3::zero());
+    let ray = Ray::new(center + Vec3::new(0.0, 10.0, 0.0), Vec3::down());
+    let hit = flattened.hit(&ray, 0.001, f64::INFINITY).unwrap();
+    assert!((hit.t - 8.0).abs() < EPSILON);
+}
+
+#[test]
+fn hidden_nodes_hide_their_subtree() {
+    let mut graph = SceneGraph::new();
+    let group = graph.add_node(graph.root(), "group", Transform::identity());
+    let child = graph.add_object(group, "child", sphere(), translation(0.0, 0.0, 5.0));
+    graph.add_object(graph.root(), "other", sphere(), Transform::identity());
+
+    graph.node_mut(group).visible = false;
+
+    assert!(!graph.is_visible(child));
+    assert_eq!(graph.flatten().hittables.len(), 1);
+    assert!(graph.build(&BVHBuilder::new(0.0, 1.0)).is_some());
+
+    graph.node_mut(graph.root()).visible = false;
+    assert!(graph.build(&BVHBuilder::new(0.0, 1.0)).is_none());
+    assert!(SceneGraph::new().build(&BVHBuilder::new(0.0, 1.0)).is_none());
+}
+
+#[test]
+fn zero_scaled_nodes_are_skipped_with_their_subtree() {
+    let mut graph = SceneGraph::new();
+    let collapsed = Transform::new(Vec3::zero(), Quaternion::identity(), Vec3::new(1.0, 0.0, 1.0));
+    let group = graph.add_object(graph.root(), "group", sphere(), collapsed);
+    graph.add_object(group, "child", sphere(), translation(0.0, 0.0, 5.0));
+    graph.add_object(graph.root(), "other", sphere(), translation(3.0, 0.0, 0.0));
+
+    assert_eq!(graph.flatten().hittables.len(), 1);
+    assert!(graph.build(&BVHBuilder::new(0.0, 1.0)).is_some());
+
+    graph.node_mut(graph.root()).transform = Transform::new(Vec3::zero(), Quaternion::identity(), Vec3::zero());
+    assert!(graph.build(&BVHBuilder::new(0.0, 1.0)).is_none());
+}
+
+#[test]
+fn nodes_can_be_edited_reparented_and_removed() {
+    let mut graph = SceneGraph::new();
+    let a = graph.add_node(graph.root(), "a", translation(1.0, 0.0, 0.0));
+    let b = graph.add_node(graph.root(), "b", translation(0.0, 1.0, 0.0));
+    let leaf = graph.add_object(a, "leaf", sphere(), Transform::identity());
+
+    graph.set_parent(leaf, b);
+    assert_eq!(graph.node(leaf).parent(), Some(b));
+    assert!(graph.node(a).children().is_empty());
+    assert_vec_close(graph.world_matrix(leaf).translation(), Vec3::new(0.0, 1.0, 0.0));
+
+    graph.node_mut(b).transform = translation(0.0, 3.0, 0.0);
+    assert_vec_close(graph.world_matrix(leaf).translation(), Vec3::new(0.0, 3.0, 0.0));
+
+    graph.remove(b);
+    assert!(!graph.contains(leaf));
+    assert_eq!(graph.find("leaf"), None);
+    assert_eq!(graph.flatten().hittables.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn reparenting_under_a_descendant_panics() {
+    let mut graph = SceneGraph::new();
+    let parent = graph.add_node(graph.root(), "parent", Transform::identity());
+    let child = graph.add_node(parent, "child", Transform::identity());
+
+    graph.set_parent(parent, child);
+}
@@ -0,0 +1,83 @@
+use raytracer::hittables::{HittableList, Sphere};
+use raytracer::rendering::{render, Camera, RenderParams, RenderStatistics};
+use raytracer::skyboxes::SolidColorSkybox;
+use raytracer::structures::{Color, Point3, Vec3};
+
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+use common::{assert_close, material};
+
+#[test]
+fn json_reports_counters_and_derived_values() {
+    let mut statistics = RenderStatistics::default();
+    statistics.primary_rays = 6;
+    statistics.secondary_rays = 3;
+    statistics.shadow_rays = 1;
+    statistics.bvh_node_tests = 40;
+    statistics.primitive_tests = 12;
+    statistics.paths = 4;
+    statistics.total_path_length = 10;
+    statistics.depth_histogram = vec![0, 1, 2, 1];
+    statistics.elapsed = Duration::from_secs(2);
+    statistics.acceleration_memory = 256;
+
+    assert_eq!(statistics.total_rays(), 10);
+    assert_close(statistics.rays_per_second(), 5.0);
+    assert_close(statistics.average_path_length(), 2.5);
+
+    let json = statistics.to_json();
+    assert!(json.starts_with('{') && json.ends_with('}'));
+
+    for field in [
+        "\"primary_rays\":6,",
+        "\"secondary_rays\":3,",
+        "\"shadow_rays\":1,",
+        "\"total_rays\":10,",
+        "\"rays_per_second\":5,",
+        "\"bvh_node_tests\":40,",
+        "\"primitive_tests\":12,",
+        "\"paths\":4,",
+        "\"average_path_length\":2.5,",
+        "\"depth_histogram\":[0,1,2,1],",
+        "\"elapsed_seconds\":2,",
+        "\"thread_seconds\":[],",
+        "\"acceleration_memory_bytes\":256}"
+    ] {
+        assert!(json.contains(field), "{} missing from {}", field, json);
+    }
+}
+
+#[test]
+fn empty_statistics_serialize_without_dividing_by_zero() {
+    let json = RenderStatistics::default().to_json();
+
+    assert!(json.contains("\"rays_per_second\":0,"));
+    assert!(json.contains("\"average_path_length\":0,"));
+    assert!(json.contains("\"depth_histogram\":[],"));
+}
+
+#[test]
+fn renders_count_one_primary_ray_per_sample() {
+    let mut world = HittableList::new();
+    world.add(Arc::new(Sphere::new(Point3::new(0.0, 0.0, -3.0), 1.0, material())));
+
+    let camera = Arc::new(Camera::new(Point3::zero(), Point3::new(0.0, 0.0, -1.0), Vec3::up(), 1.0, 1.5, 0.0, 1.0, 0.0, 1.0));
+    let skybox = Arc::new(SolidColorSkybox::new(Color::new(0.5, 0.7, 1.0)));
+    let params = RenderParams {
+        image_width: 6,
+        image_height: 4,
+        num_samples: 3,
+        max_ray_depth: 4,
+        ..Default::default()
+    };
+
+    let statistics = render(Arc::new(world), skybox, camera, &params, |_, _| {}).statistics;
+
+    assert_eq!(statistics.primary_rays, 6 * 4 * 3);
+    assert_eq!(statistics.paths, 6 * 4 * 3);
+    assert_eq!(statistics.depth_histogram.iter().sum::<u64>(), statistics.paths);
+    assert!(!statistics.thread_times.is_empty());
+    assert!(statistics.to_json().contains(&format!("\"primary_rays\":{},", 6 * 4 * 3)));
+}
@@ -0,0 +1,85 @@
+use raytracer::hittables::{Hittable, SubdivisionSurface};
+use raytracer::rendering::Camera;
+use raytracer::structures::{Color, Point3, Ray, Vec3};
+use raytracer::textures::SolidColor;
+
+use std::sync::Arc;
+
+mod common;
+use common::{EPSILON, material};
+
+fn cube() -> SubdivisionSurface {
+    let positions = (0..8).map(|i| Point3::new(
+        if i & 1 == 0 { -1.0 } else { 1.0 },
+        if i & 2 == 0 { -1.0 } else { 1.0 },
+        if i & 4 == 0 { -1.0 } else { 1.0 }
+    )).collect();
+
+    let faces = vec![
+        vec![0, 2, 3, 1], vec![4, 5, 7, 6],
+        vec![0, 1, 5, 4], vec![2, 6, 7, 3],
+        vec![0, 4, 6, 2], vec![1, 3, 7, 5]
+    ];
+
+    SubdivisionSurface::new(positions, faces, material())
+}
+
+fn camera(distance: f64) -> Camera {
+    Camera::new(Point3::new(0.0, 0.0, distance), Point3::zero(), Vec3::up(), 0.8, 1.0, 0.0, distance, 0.0, 1.0)
+}
+
+#[test]
+fn catmull_clark_cube_refines() {
+    let mesh = cube().with_level(1).tessellate();
+
+    assert_eq!(mesh.vertex_count(), 26);
+    assert_eq!(mesh.triangle_count(), 48);
+
+    let corner = mesh.positions_at(0.0)[7];
+    assert!((corner - Point3::new(5.0 / 9.0, 5.0 / 9.0, 5.0 / 9.0)).length() < EPSILON);
+}
+
+#[test]
+fn smooth_normals_and_symmetric_hits() {
+    let surface = cube().with_level(3);
+    let hit = surface.hit(&Ray::new(Point3::new(5.0, 0.01, 0.02), Vec3::new(-1.0, 0.0, 0.0)), 0.001, f64::INFINITY).unwrap();
+    let other = surface.hit(&Ray::new(Point3::new(0.02, -5.0, 0.01), Vec3::new(0.0, 1.0, 0.0)), 0.001, f64::INFINITY).unwrap();
+
+    assert!((hit.normal - Vec3::right()).length() < 0.05);
+    assert!((other.normal - Vec3::down()).length() < 0.05);
+    assert!((hit.t - other.t).abs() < 1e-9);
+    assert!(hit.point.x < 1.0 && hit.point.x > 0.5);
+    assert!(surface.mesh().normals().is_some());
+}
+
+#[test]
+fn boundary_vertices_stay_on_open_cages() {
+    let positions = (0..9).map(|i| Point3::new((i % 3) as f64, 0.0, (i / 3) as f64)).collect();
+    let faces = vec![vec![0, 3, 4, 1], vec![1, 4, 5, 2], vec![3, 6, 7, 4], vec![4, 7, 8, 5]];
+    let mesh = SubdivisionSurface::new(positions, faces, material()).with_uvs((0..9).map(|i| ((i % 3) as f64 / 2.0, (i / 3) as f64 / 2.0)).collect()).with_level(2).tessellate();
+
+    assert!(mesh.positions_at(0.0).iter().all(|point| point.y.abs() < EPSILON));
+    assert_eq!(mesh.positions_at(0.0)[0], Point3::new(0.0, 0.0, 0.0));
+
+    let hit = mesh.hit(&Ray::new(Point3::new(1.03, 1.0, 0.52), Vec3::new(0.0, -1.0, 0.0)), 0.001, f64::INFINITY).unwrap();
+    assert!((hit.u - 0.5).abs() < 0.05 && (hit.v - 0.25).abs() < 0.05);
+}
+
+#[test]
+fn displacement_pushes_along_normals() {
+    let ray = Ray::new(Point3::new(5.0, 0.01, 0.02), Vec3::new(-1.0, 0.0, 0.0));
+    let smooth = cube().with_level(2).hit(&ray, 0.001, f64::INFINITY).unwrap();
+    let displaced = cube().with_level(2).with_displacement(Arc::new(SolidColor::new(Color::new(1.0, 1.0, 1.0))), 0.25).hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+    assert!((smooth.t - displaced.t - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn adaptive_level_follows_screen_size() {
+    let near = cube().with_adaptive_level(&camera(3.0), 1080, 2.0, 6);
+    let far = cube().with_adaptive_level(&camera(3000.0), 1080, 2.0, 6);
+
+    assert!(near.level() > far.level());
+    assert_eq!(far.level(), 0);
+    assert!(near.level() <= 6);
+}
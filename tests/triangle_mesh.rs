@@ -0,0 +1,59 @@
+use raytracer::hittables::{Hittable, TriangleMesh};
+use raytracer::structures::{Point3, Ray, Vec3};
+
+mod common;
+use common::{EPSILON, material};
+
+fn quad(z: f64) -> Vec<Point3> {
+    vec![Point3::new(-1.0, -1.0, z), Point3::new(1.0, -1.0, z), Point3::new(1.0, 1.0, z), Point3::new(-1.0, 1.0, z)]
+}
+
+fn quad_indices() -> Vec<[usize; 3]> {
+    vec![[0, 1, 2], [0, 2, 3]]
+}
+
+#[test]
+fn static_mesh_hits_both_triangles() {
+    let mesh = TriangleMesh::new(quad(0.0), quad_indices(), material()).with_uvs(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+
+    for &(x, y) in &[(0.5, -0.5), (-0.5, 0.5)] {
+        let ray = Ray::new(Point3::new(x, y, 5.0), Vec3::back());
+        let hit = mesh.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+        assert!((hit.t - 5.0).abs() < EPSILON);
+        assert!((hit.u - (x + 1.0) / 2.0).abs() < EPSILON && (hit.v - (y + 1.0) / 2.0).abs() < EPSILON);
+        assert!((hit.normal.z.abs() - 1.0).abs() < EPSILON);
+    }
+
+    assert!(mesh.hit(&Ray::new(Point3::new(1.5, 0.0, 5.0), Vec3::back()), 0.001, f64::INFINITY).is_none());
+    assert!(mesh.occluded(&Ray::new(Point3::new(0.0, 0.0, 5.0), Vec3::back()), 0.001, 6.0));
+    assert!(!mesh.occluded(&Ray::new(Point3::new(0.0, 0.0, 5.0), Vec3::back()), 0.001, 4.0));
+}
+
+#[test]
+fn deforming_mesh_follows_ray_time() {
+    let samples = vec![quad(0.0), quad(1.0), quad(4.0)];
+    let mesh = TriangleMesh::with_motion(samples, quad_indices(), material(), 0.0, 1.0);
+
+    for &(time, z) in &[(0.0, 0.0), (0.25, 0.5), (0.5, 1.0), (0.75, 2.5), (1.0, 4.0)] {
+        let ray = Ray::with_time(Point3::new(0.2, 0.3, 10.0), Vec3::back(), time);
+        let hit = mesh.hit(&ray, 0.001, f64::INFINITY).unwrap();
+
+        assert!((hit.point.z - z).abs() < EPSILON, "time {} hit {}", time, hit.point.z);
+    }
+}
+
+#[test]
+fn bounds_are_limited_to_the_requested_interval() {
+    let samples = vec![quad(0.0), quad(1.0), quad(4.0)];
+    let mesh = TriangleMesh::with_motion(samples, quad_indices(), material(), 0.0, 1.0);
+
+    let whole = mesh.bounding_box(0.0, 1.0).unwrap();
+    assert!((whole.min.z - 0.0).abs() < EPSILON && (whole.max.z - 4.0).abs() < EPSILON);
+
+    let first_half = mesh.bounding_box(0.0, 0.5).unwrap();
+    assert!((first_half.min.z - 0.0).abs() < EPSILON && (first_half.max.z - 1.0).abs() < EPSILON);
+
+    let middle = mesh.bounding_box(0.25, 0.75).unwrap();
+    assert!((middle.min.z - 0.5).abs() < EPSILON && (middle.max.z - 2.5).abs() < EPSILON);
+}